@@ -0,0 +1,117 @@
+use crate::{Error, Input};
+use std::str::FromStr;
+
+/// Command line arguments.
+///
+/// Options are written `--name value`, `--name=value` or `--name` for flags.
+/// Positional arguments have to come before the options.
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    pub fn from_env() -> Result<Self, Error> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Error> {
+        let mut result = Args::default();
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            if let Some(option) = arg.strip_prefix("--") {
+                if let Some(separator) = option.find('=') {
+                    result.options.push((
+                        option[..separator].to_owned(),
+                        Some(option[separator + 1..].to_owned()),
+                    ));
+                } else {
+                    let value = match args.peek() {
+                        Some(value) if !value.starts_with("--") => args.next(),
+                        _ => None,
+                    };
+                    result.options.push((option.to_owned(), value));
+                }
+            } else {
+                result.positional.push(arg);
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(n, _)| n == name)
+    }
+
+    /// Returns the last value given to an option.
+    pub fn value(&self, name: &str) -> Result<Option<&str>, Error> {
+        match self.options.iter().rev().find(|(n, _)| n == name) {
+            Some((_, Some(value))) => Ok(Some(value)),
+            Some((_, None)) => Err(Error::MissingArgumentValue(name.to_owned())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn values(&self, name: &str) -> Result<Vec<&str>, Error> {
+        self.options
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, value)| {
+                value
+                    .as_deref()
+                    .ok_or_else(|| Error::MissingArgumentValue(name.to_owned()))
+            })
+            .collect()
+    }
+
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error> {
+        self.value(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| Error::InvalidArgumentValue(name.to_owned(), value.to_owned()))
+            })
+            .transpose()
+    }
+
+    /// Input given with `--input`, defaults to the `input` file of the current directory.
+    pub fn input(&self) -> Result<Input, Error> {
+        Ok(self.parse_value("input")?.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|&a| a.to_owned())).unwrap()
+    }
+
+    #[test]
+    fn can_parse_options() {
+        let args = args(&["run", "1", "--input", "example_input", "--k=3", "--verbose"]);
+        assert_eq!(args.positional(), &["run", "1"]);
+        assert_eq!(args.value("input").unwrap(), Some("example_input"));
+        assert_eq!(args.parse_value::<usize>("k").unwrap(), Some(3));
+        assert!(args.flag("verbose"));
+        assert!(!args.flag("quiet"));
+    }
+
+    #[test]
+    fn defaults_to_input_file() {
+        assert_eq!(args(&[]).input().unwrap(), Input::default());
+        assert_eq!(args(&["--input", "-"]).input().unwrap(), Input::Stdin);
+    }
+
+    #[test]
+    fn reports_missing_value() {
+        assert!(args(&["--input"]).input().is_err());
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// Cells whose neighbour counts are taken into account by an automaton.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Neighbourhood {
+    /// Every cell at a distance of 1 on each axis, diagonals included.
+    Moore,
+    /// The 2 cells at a distance of 1 on each axis.
+    VonNeumann,
+    /// The 6 cells around a hexagon in cube coordinates, `x + y + z` being 0.
+    /// Only defined in 3 dimensions.
+    Hex,
+}
+
+impl Neighbourhood {
+    pub fn offsets<const N: usize>(self) -> Result<Vec<[i32; N]>, NeighbourhoodError> {
+        Ok(match self {
+            Neighbourhood::Moore => (0..3usize.pow(N as u32))
+                .map(|i| {
+                    let mut offset = [0; N];
+                    for (axis, value) in offset.iter_mut().enumerate() {
+                        *value = (i / 3usize.pow(axis as u32) % 3) as i32 - 1;
+                    }
+                    offset
+                })
+                .filter(|offset| offset.iter().any(|&v| v != 0))
+                .collect(),
+            Neighbourhood::VonNeumann => (0..N)
+                .flat_map(|axis| {
+                    [-1, 1].iter().map(move |&v| {
+                        let mut offset = [0; N];
+                        offset[axis] = v;
+                        offset
+                    })
+                })
+                .collect(),
+            Neighbourhood::Hex => {
+                if N != 3 {
+                    return Err(NeighbourhoodError::UnsupportedDimensions(self, N));
+                }
+                Neighbourhood::Moore
+                    .offsets::<N>()?
+                    .into_iter()
+                    .filter(|offset| offset.iter().sum::<i32>() == 0)
+                    .collect()
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NeighbourhoodError {
+    /// Neighbourhood and number of dimensions it was asked for.
+    UnsupportedDimensions(Neighbourhood, usize),
+}
+
+impl From<NeighbourhoodError> for crate::Error {
+    fn from(error: NeighbourhoodError) -> Self {
+        crate::Error::solver(error)
+    }
+}
+
+/// Birth/survival rule written `B3/S23`: a dead cell with 3 live neighbours
+/// becomes alive, a live cell with 2 or 3 live neighbours stays alive.
+///
+/// Each digit is a count, unless the counts are separated by commas, which is
+/// needed for counts of 10 or more: `B3,12/S2,3`.
+///
+/// Births on 0 live neighbours are rejected, the automaton only visiting the
+/// cells next to live ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn is_born(&self, live_neighbours: usize) -> bool {
+        self.birth.contains(&live_neighbours)
+    }
+
+    pub fn survives(&self, live_neighbours: usize) -> bool {
+        self.survival.contains(&live_neighbours)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split_s = s.trim().split('/');
+        let mut counts = |prefix| {
+            let counts = split_s
+                .next()
+                .and_then(|counts| counts.strip_prefix(prefix))
+                .ok_or(ParseRuleError::MissingPart(prefix))?;
+            let counts: Vec<&str> = if counts.contains(',') {
+                counts.split(',').collect()
+            } else {
+                counts
+                    .char_indices()
+                    .map(|(i, c)| &counts[i..i + c.len_utf8()])
+                    .collect()
+            };
+            counts
+                .into_iter()
+                .map(|count| {
+                    count
+                        .parse()
+                        .map_err(|_| ParseRuleError::InvalidCount(count.into()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let birth = counts('B')?;
+        if birth.contains(&0) {
+            return Err(ParseRuleError::BirthWithoutNeighbours);
+        }
+
+        Ok(Rule {
+            birth,
+            survival: counts('S')?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseRuleError {
+    MissingPart(char),
+    InvalidCount(String),
+    /// A birth on 0 live neighbours, which would fill the unbounded grid.
+    BirthWithoutNeighbours,
+}
+
+impl From<ParseRuleError> for crate::Error {
+    fn from(error: ParseRuleError) -> Self {
+        crate::Error::solver(error)
+    }
+}
+
+/// Cellular automaton on an unbounded `N` dimensional grid, only the live cells are stored.
+#[derive(Debug, Clone)]
+pub struct Automaton<const N: usize> {
+    live_cells: HashSet<[i32; N]>,
+    offsets: Vec<[i32; N]>,
+    rule: Rule,
+}
+
+impl<const N: usize> Automaton<N> {
+    pub fn new(neighbourhood: Neighbourhood, rule: Rule) -> Result<Self, NeighbourhoodError> {
+        Ok(Self {
+            live_cells: HashSet::new(),
+            offsets: neighbourhood.offsets()?,
+            rule,
+        })
+    }
+
+    pub fn live_cells(&self) -> impl Iterator<Item = &[i32; N]> {
+        self.live_cells.iter()
+    }
+
+    pub fn live_count(&self) -> usize {
+        self.live_cells.len()
+    }
+
+    pub fn is_alive(&self, cell: &[i32; N]) -> bool {
+        self.live_cells.contains(cell)
+    }
+
+    pub fn set_alive(&mut self, cell: [i32; N], alive: bool) {
+        if alive {
+            self.live_cells.insert(cell);
+        } else {
+            self.live_cells.remove(&cell);
+        }
+    }
+
+    pub fn toggle(&mut self, cell: [i32; N]) {
+        let alive = self.is_alive(&cell);
+        self.set_alive(cell, !alive);
+    }
+
+    /// Computes the next generation.
+    ///
+    /// Live neighbour counts are accumulated from the live cells, so only
+    /// the cells next to a live cell are visited.
+    pub fn step(&mut self) {
+        let mut live_neighbours: HashMap<[i32; N], usize> = HashMap::new();
+        for cell in &self.live_cells {
+            for offset in &self.offsets {
+                let mut neighbour = *cell;
+                for (value, offset) in neighbour.iter_mut().zip(offset) {
+                    *value += offset;
+                }
+                *live_neighbours.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let mut next_live_cells: HashSet<[i32; N]> = live_neighbours
+            .iter()
+            .filter(|(cell, &count)| {
+                if self.live_cells.contains(*cell) {
+                    self.rule.survives(count)
+                } else {
+                    self.rule.is_born(count)
+                }
+            })
+            .map(|(cell, _)| *cell)
+            .collect();
+        if self.rule.survives(0) {
+            next_live_cells.extend(
+                self.live_cells
+                    .iter()
+                    .filter(|cell| !live_neighbours.contains_key(*cell)),
+            );
+        }
+
+        self.live_cells = next_live_cells;
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_rules() {
+        let rule: Rule = "B3/S23".parse().unwrap();
+        assert!(rule.is_born(3));
+        assert!(!rule.is_born(2));
+        assert!(rule.survives(2) && rule.survives(3));
+        assert!(!rule.survives(4));
+
+        assert_eq!("B2/S".parse::<Rule>().unwrap().survival, vec![]);
+        assert_eq!("S23".parse::<Rule>(), Err(ParseRuleError::MissingPart('B')));
+        assert_eq!(
+            "B3/S2x".parse::<Rule>(),
+            Err(ParseRuleError::InvalidCount("x".into()))
+        );
+        assert_eq!(
+            "B03/S23".parse::<Rule>(),
+            Err(ParseRuleError::BirthWithoutNeighbours)
+        );
+        assert!("B3/S0".parse::<Rule>().is_ok());
+    }
+
+    #[test]
+    fn can_parse_comma_separated_counts() {
+        let rule: Rule = "B3,12/S2,3,80".parse().unwrap();
+        assert_eq!(rule.birth, vec![3, 12]);
+        assert_eq!(rule.survival, vec![2, 3, 80]);
+        assert_eq!(
+            "B3,/S2".parse::<Rule>(),
+            Err(ParseRuleError::InvalidCount("".into()))
+        );
+    }
+
+    #[test]
+    fn neighbourhoods_have_expected_sizes() {
+        assert_eq!(Neighbourhood::Moore.offsets::<2>().unwrap().len(), 8);
+        assert_eq!(Neighbourhood::Moore.offsets::<4>().unwrap().len(), 80);
+        assert_eq!(Neighbourhood::VonNeumann.offsets::<3>().unwrap().len(), 6);
+        assert_eq!(Neighbourhood::Hex.offsets::<3>().unwrap().len(), 6);
+        assert_eq!(
+            Neighbourhood::Hex.offsets::<2>(),
+            Err(NeighbourhoodError::UnsupportedDimensions(
+                Neighbourhood::Hex,
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let mut automaton =
+            Automaton::<2>::new(Neighbourhood::Moore, "B3/S23".parse().unwrap()).unwrap();
+        for x in -1..=1 {
+            automaton.set_alive([x, 0], true);
+        }
+
+        automaton.step();
+        let mut cells: Vec<_> = automaton.live_cells().copied().collect();
+        cells.sort();
+        assert_eq!(cells, [[0, -1], [0, 0], [0, 1]]);
+
+        automaton.step();
+        assert!(automaton.is_alive(&[-1, 0]) && automaton.is_alive(&[1, 0]));
+        assert_eq!(automaton.live_count(), 3);
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::Range;
+
+/// Set of the integers below a fixed capacity, stored as one bit each.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BitSet {
+    capacity: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        BitSet {
+            capacity,
+            words: vec![0; capacity.div_ceil(64)],
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds `value`, returning whether it was absent.
+    ///
+    /// Panics if `value` is not below the capacity.
+    pub fn insert(&mut self, value: usize) -> bool {
+        assert!(value < self.capacity, "{} is out of the set", value);
+        let word = &mut self.words[value / 64];
+        let absent = *word & (1 << (value % 64)) == 0;
+        *word |= 1 << (value % 64);
+        absent
+    }
+
+    /// Whether `value` is in the set, values beyond the capacity never being.
+    pub fn contains(&self, value: usize) -> bool {
+        value < self.capacity && self.words[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    /// Number of values in the set.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Values of the set, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    /// Values below the capacity missing from the set, in increasing order.
+    pub fn zeros(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.capacity).filter(move |&value| !self.contains(value))
+    }
+
+    /// Longest ranges of values missing from the set within `range`, in
+    /// increasing order.
+    pub fn zero_runs(&self, range: Range<usize>) -> impl Iterator<Item = Range<usize>> + '_ {
+        let end = range.end;
+        let mut value = range.start;
+        std::iter::from_fn(move || {
+            while value < end && self.contains(value) {
+                value += 1;
+            }
+            let start = value;
+            while value < end && !self.contains(value) {
+                value += 1;
+            }
+            Some(start..value).filter(|run| !run.is_empty())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_query_bits() {
+        let mut set = BitSet::new(130);
+        for value in [0, 3, 4, 64, 129] {
+            assert!(set.insert(value));
+        }
+        assert!(!set.insert(4));
+        assert!(set.contains(64) && !set.contains(65) && !set.contains(130));
+        assert_eq!(set.count(), 5);
+        assert_eq!(set.ones().collect::<Vec<_>>(), [0, 3, 4, 64, 129]);
+        assert_eq!(set.zeros().take(3).collect::<Vec<_>>(), [1, 2, 5]);
+        assert_eq!(set.zeros().count(), 125);
+        assert_eq!(
+            set.zero_runs(2..66).collect::<Vec<_>>(),
+            [2..3, 5..64, 65..66]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
+
+/// Error shared by every day.
+///
+/// Day specific errors are converted into [`Error::Solver`] so that every binary
+/// reports its failures the same way.
+pub enum Error {
+    InputFileOpenError(PathBuf, std::io::Error),
+    InputReadError(std::io::Error),
+    MissingArgumentValue(String),
+    InvalidArgumentValue(String, String),
+    PartNotSolved(u8),
+    /// Malformed input: line, column, offending text and description.
+    Parse(usize, usize, String, String),
+    Solver(String),
+    Context(String, Box<Error>),
+}
+
+impl Error {
+    pub fn solver(error: impl Debug) -> Self {
+        Error::Solver(format!("{:?}", error))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InputFileOpenError(path, e) => {
+                write!(f, "cannot open input file `{}`: {}", path.display(), e)
+            }
+            Error::InputReadError(e) => write!(f, "cannot read input: {}", e),
+            Error::MissingArgumentValue(name) => write!(f, "missing value for `--{}`", name),
+            Error::InvalidArgumentValue(name, value) => {
+                write!(f, "invalid value `{}` for `--{}`", value, name)
+            }
+            Error::PartNotSolved(part) => write!(f, "part {} is not solved", part),
+            Error::Parse(line, column, text, message) => write!(
+                f,
+                "line {}, column {}: {} at `{}`",
+                line, column, message, text
+            ),
+            Error::Solver(message) => write!(f, "{}", message),
+            Error::Context(context, e) => write!(f, "{}: {}", context, e),
+        }
+    }
+}
+
+// `main` prints the `Debug` representation of the returned error, so it is
+// forwarded to `Display` to keep the messages readable.
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InputFileOpenError(_, e) | Error::InputReadError(e) => Some(e),
+            Error::Context(_, e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Adds a context message to the error of a `Result`.
+pub trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Error>> Context<T> for Result<T, E> {
+    fn context(self, context: impl Into<String>) -> Result<T, Error> {
+        self.map_err(|e| Error::Context(context.into(), Box::new(e.into())))
+    }
+}
@@ -0,0 +1,363 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Offsets of the 4 orthogonal neighbours of a cell.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbours of a cell, diagonals included.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Cell of a grid that can be read from and written to a single byte of text.
+pub trait GridCell: Sized {
+    fn from_byte(byte: u8) -> Option<Self>;
+    fn to_byte(&self) -> u8;
+}
+
+/// `#` is `true`, `.` is `false`.
+impl GridCell for bool {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'#' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_byte(&self) -> u8 {
+        if *self {
+            b'#'
+        } else {
+            b'.'
+        }
+    }
+}
+
+/// Any printable ASCII byte is kept as is.
+impl GridCell for u8 {
+    fn from_byte(byte: u8) -> Option<Self> {
+        Some(byte).filter(u8::is_ascii_graphic)
+    }
+
+    fn to_byte(&self) -> u8 {
+        *self
+    }
+}
+
+/// Rectangular grid of cells stored row by row.
+///
+/// Positions are `(x, y)` pairs, `x` being the column and `y` the row, `(0, 0)`
+/// being the top left cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Cell count != width * height");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Cell at the given position, the grid being repeated infinitely in every direction.
+    ///
+    /// Panics if the grid is empty, which a parsed grid never is.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Positions of the orthogonal neighbours of a cell that are inside the grid.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the neighbours of a cell, diagonals included, that are inside the grid.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.index(x, y).map(|_| (x as usize, y as usize))
+        })
+    }
+
+    /// Cells seen from a cell when looking in a direction, until the edge of the grid.
+    /// The starting cell isn't included.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        (1..)
+            .map(move |step| (x as isize + dx * step, y as isize + dy * step))
+            .map_while(move |(x, y)| self.get(x, y).map(|cell| ((x as usize, y as usize), cell)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(y as usize * self.width + x as usize)
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn column(&self, x: usize) -> Vec<T> {
+        (0..self.height).map(|y| self.row(y)[x].clone()).collect()
+    }
+
+    /// Grid rotated by 90 degrees clockwise.
+    pub fn rotated(&self) -> Self {
+        self.transformed(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Grid mirrored along its vertical axis.
+    pub fn flipped_x(&self) -> Self {
+        self.transformed(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Grid mirrored along its horizontal axis.
+    pub fn flipped_y(&self) -> Self {
+        self.transformed(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// The 8 rotations and reflections of the grid.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        let mut grid = self.clone();
+        for _ in 0..4 {
+            orientations.push(grid.flipped_x());
+            let rotated = grid.rotated();
+            orientations.push(grid);
+            grid = rotated;
+        }
+
+        orientations
+    }
+
+    /// Builds a `width` x `height` grid whose cell `(x, y)` is the cell
+    /// `source(x, y)` of this grid.
+    fn transformed(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (source_x, source_y) = source(x, y);
+                self.cells[source_y * self.width + source_x].clone()
+            })
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T: GridCell> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (row, line) in s.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            match width {
+                None => width = Some(line.len()),
+                Some(width) if width != line.len() => {
+                    return Err(ParseGridError::UnevenRow(row + 1))
+                }
+                _ => {}
+            }
+
+            for (column, byte) in line.bytes().enumerate() {
+                cells.push(T::from_byte(byte).ok_or(ParseGridError::InvalidCell(
+                    row + 1,
+                    column + 1,
+                    byte as char,
+                ))?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(
+            width
+                .filter(|&width| width > 0)
+                .ok_or(ParseGridError::Empty)?,
+            height,
+            cells,
+        ))
+    }
+}
+
+impl<T: GridCell> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_byte() as char)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a grid, lines and columns start at 1.
+#[derive(Debug, PartialEq)]
+pub enum ParseGridError {
+    Empty,
+    UnevenRow(usize),
+    InvalidCell(usize, usize, char),
+}
+
+impl From<ParseGridError> for crate::Error {
+    fn from(error: ParseGridError) -> Self {
+        crate::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "#..\n.#.\n##.\n#..";
+
+    #[test]
+    fn display_round_trips() {
+        let grid: Grid<bool> = TEXT.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.to_string(), TEXT);
+    }
+
+    #[test]
+    fn reports_invalid_grids() {
+        assert_eq!("".parse::<Grid<bool>>(), Err(ParseGridError::Empty));
+        assert_eq!("\n".parse::<Grid<bool>>(), Err(ParseGridError::Empty));
+        assert_eq!(
+            "#.\n#".parse::<Grid<bool>>(),
+            Err(ParseGridError::UnevenRow(2))
+        );
+        assert_eq!(
+            "#.\n#L".parse::<Grid<bool>>(),
+            Err(ParseGridError::InvalidCell(2, 2, 'L'))
+        );
+    }
+
+    #[test]
+    fn can_access_cells() {
+        let grid: Grid<bool> = TEXT.parse().unwrap();
+        assert_eq!(grid.get(1, 1), Some(&true));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert!(*grid.get_wrapping(3, 4));
+        assert!(*grid.get_wrapping(-2, -3));
+        assert_eq!(grid.column(0), vec![true, false, true, true]);
+    }
+
+    #[test]
+    fn can_iterate_neighbours() {
+        let grid: Grid<bool> = TEXT.parse().unwrap();
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbours_8(1, 1)
+                .filter(|&(x, y)| grid.row(y)[x])
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn can_cast_rays() {
+        let grid: Grid<bool> = TEXT.parse().unwrap();
+        let ray: Vec<_> = grid.ray(0, 0, (1, 1)).collect();
+        assert_eq!(ray, [((1, 1), &true), ((2, 2), &false)]);
+        assert_eq!(grid.ray(0, 0, (0, -1)).count(), 0);
+    }
+
+    #[test]
+    fn can_transform() {
+        let grid: Grid<bool> = TEXT.parse().unwrap();
+        assert_eq!(grid.rotated().to_string(), "##.#\n.##.\n....");
+        assert_eq!(grid.flipped_x().to_string(), "..#\n.#.\n.##\n..#");
+        assert_eq!(grid.flipped_y().to_string(), "#..\n##.\n.#.\n#..");
+        assert_eq!(grid.rotated().rotated().rotated().rotated(), grid);
+
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert!(orientations.contains(&grid.flipped_y()));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+const DEFAULT_INPUT_PATH: &str = "input";
+
+/// Source of a puzzle input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Input {
+    pub fn read_to_string(&self) -> Result<String, Error> {
+        let mut content = String::new();
+        self.reader()?
+            .read_to_string(&mut content)
+            .map_err(Error::InputReadError)?;
+        Ok(content)
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead + '_>, Error> {
+        Ok(match self {
+            Input::Path(path) => Box::new(BufReader::new(
+                File::open(path).map_err(|e| Error::InputFileOpenError(path.clone(), e))?,
+            )),
+            Input::Stdin => Box::new(BufReader::new(std::io::stdin())),
+            Input::Text(text) => Box::new(Cursor::new(text.as_bytes())),
+        })
+    }
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Input::Path(DEFAULT_INPUT_PATH.into())
+    }
+}
+
+/// `-` designates the standard input, anything else is a path.
+impl FromStr for Input {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Input::Stdin,
+            path => Input::Path(path.into()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_read_text_input() {
+        let input = Input::Text("1\n2\n".into());
+        assert_eq!(input.read_to_string().unwrap(), "1\n2\n");
+        assert_eq!(input.reader().unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn reports_missing_input_file() {
+        let input: Input = "does/not/exist".parse().unwrap();
+        match input.read_to_string() {
+            Err(Error::InputFileOpenError(path, _)) => {
+                assert_eq!(path, PathBuf::from("does/not/exist"))
+            }
+            _ => panic!("Expected an input file open error"),
+        }
+    }
+}
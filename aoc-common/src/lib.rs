@@ -0,0 +1,21 @@
+mod args;
+mod automaton;
+mod bitset;
+mod error;
+mod grid;
+mod input;
+mod parse;
+mod records;
+mod solution;
+mod trace;
+
+pub use args::Args;
+pub use automaton::{Automaton, Neighbourhood, NeighbourhoodError, ParseRuleError, Rule};
+pub use bitset::BitSet;
+pub use error::{Context, Error};
+pub use grid::{Grid, GridCell, ParseGridError, NEIGHBOURS_4, NEIGHBOURS_8};
+pub use input::Input;
+pub use parse::{parse_lines, ParseError};
+pub use records::{records, split_records, Record, Records};
+pub use solution::Solution;
+pub use trace::{is_tracing, set_tracing};
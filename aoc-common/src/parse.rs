@@ -0,0 +1,94 @@
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error located in the input, lines and columns start at 1.
+///
+/// Parsers of a single line build it with [`ParseError::new`] and the line number
+/// is filled in by [`parse_lines`], or by the caller with [`ParseError::at_line`].
+#[derive(Debug, PartialEq)]
+pub struct ParseError<E> {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: E,
+}
+
+impl<E> ParseError<E> {
+    /// `token` is the offending part of `line`, its column is computed from its
+    /// position in `line` when it is a subslice of it, and is 1 otherwise.
+    pub fn new(line: &str, token: &str, kind: E) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() && token.len() <= line.len() - offset {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Self {
+            line: 0,
+            column,
+            text: token.to_string(),
+            kind,
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl<E: Debug> Display for ParseError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {:?} at `{}`",
+            self.line, self.column, self.kind, self.text
+        )
+    }
+}
+
+impl<E: Debug> From<ParseError<E>> for crate::Error {
+    fn from(error: ParseError<E>) -> Self {
+        crate::Error::Parse(
+            error.line,
+            error.column,
+            error.text,
+            format!("{:?}", error.kind),
+        )
+    }
+}
+
+/// Parses every line of `input`, the errors being located on the failing line.
+pub fn parse_lines<T, E>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError<E>>,
+) -> Result<Vec<T>, ParseError<E>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_errors() {
+        let error = parse_lines("1 2\n3 x 5", |line| {
+            line.split(' ')
+                .map(|n| n.parse::<u32>().map_err(|_| ParseError::new(line, n, ())))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+        assert_eq!(error.to_string(), "line 2, column 3: () at `x`");
+    }
+
+    #[test]
+    fn uses_first_column_for_foreign_tokens() {
+        assert_eq!(ParseError::new("abc", "def", ()).column, 1);
+        assert_eq!(ParseError::new("abc", &"abc"[3..], ()).column, 4);
+    }
+}
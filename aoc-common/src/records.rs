@@ -0,0 +1,116 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+/// Consecutive non blank lines of a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Number of the first line, lines starting at 1.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Record {
+    /// Lines along with their number.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        (self.first_line..).zip(self.lines.iter().map(String::as_str))
+    }
+
+    /// Numbers of the first and last lines.
+    pub fn span(&self) -> RangeInclusive<usize> {
+        self.first_line..=self.first_line + self.lines.len() - 1
+    }
+
+    /// Lines joined with `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// Iterator over the records of a text separated by blank lines, see [`records`].
+#[derive(Debug)]
+pub struct Records<R> {
+    reader: R,
+    line_number: usize,
+    line: String,
+}
+
+/// Reads the records separated by blank lines one line at a time.
+///
+/// Trailing whitespace is removed from every line, including the `\r` of `\r\n`
+/// line endings, so that lines of whitespace only are blank too.
+pub fn records<R: BufRead>(reader: R) -> Records<R> {
+    Records {
+        reader,
+        line_number: 0,
+        line: String::new(),
+    }
+}
+
+/// Records of a string, see [`records`].
+pub fn split_records(input: &str) -> impl Iterator<Item = Record> + '_ {
+    records(input.as_bytes()).map(|record| record.expect("Reading a string cannot fail"))
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = std::io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record: Option<Record> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return record.map(Ok),
+                Ok(_) => self.line_number += 1,
+                Err(e) => return Some(Err(e)),
+            }
+
+            let line = self.line.trim_end();
+            match &mut record {
+                None if line.is_empty() => {}
+                None => {
+                    record = Some(Record {
+                        first_line: self.line_number,
+                        lines: vec![line.to_owned()],
+                    })
+                }
+                Some(_) if line.is_empty() => return record.map(Ok),
+                Some(record) => record.lines.push(line.to_owned()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_split_records() {
+        let records: Vec<Record> =
+            split_records("\r\nab\r\nc  \r\n \t\r\n\r\n\r\nd\ne\n\nf\n\n  \n").collect();
+        assert_eq!(
+            records,
+            [
+                Record {
+                    first_line: 2,
+                    lines: vec!["ab".into(), "c".into()]
+                },
+                Record {
+                    first_line: 7,
+                    lines: vec!["d".into(), "e".into()]
+                },
+                Record {
+                    first_line: 10,
+                    lines: vec!["f".into()]
+                },
+            ]
+        );
+        assert_eq!(records[0].span(), 2..=3);
+        assert_eq!(records[1].text(), "d\ne");
+        assert_eq!(
+            records[1].numbered_lines().collect::<Vec<_>>(),
+            [(7, "d"), (8, "e")]
+        );
+        assert_eq!(split_records("\n \n").count(), 0);
+    }
+}
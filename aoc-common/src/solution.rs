@@ -0,0 +1,16 @@
+use crate::Error;
+
+/// Solver of both parts of a day.
+///
+/// Answers are returned as strings as some days have textual answers.
+pub trait Solution {
+    fn part1(&self, input: &str) -> Result<String, Error> {
+        let _ = input;
+        Err(Error::PartNotSolved(1))
+    }
+
+    fn part2(&self, input: &str) -> Result<String, Error> {
+        let _ = input;
+        Err(Error::PartNotSolved(2))
+    }
+}
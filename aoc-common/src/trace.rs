@@ -0,0 +1,24 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+/// Enables the traces written by the solvers with [`trace!`], they are disabled
+/// by default.
+pub fn set_tracing(enabled: bool) {
+    TRACING.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_tracing() -> bool {
+    TRACING.load(Ordering::Relaxed)
+}
+
+/// Writes a line on stderr when tracing is enabled, the arguments are not
+/// formatted otherwise.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::is_tracing() {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,51 @@
+use aoc::Answers;
+use aoc_common::{Error, Input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+
+type Solver = fn(&str) -> Result<String, Error>;
+
+/// Alternative solvers of a part, by day and part, benchmarked next to the main
+/// ones once their answer is checked.
+const VARIANTS: &[(u8, u8, &str, Solver)] = &[
+    (1, 1, "part1_naive", |input| {
+        Ok(day1::part1_naive(input)?.to_string())
+    }),
+    (8, 2, "part2_backtracking", |input| {
+        Ok(day8::part2_backtracking(input)?.to_string())
+    }),
+];
+
+fn solutions(c: &mut Criterion) {
+    for day in 1..=aoc::LAST_DAY {
+        let solution = aoc::solution(day).expect("Missing solution");
+        let answers = Answers::load(day).expect("Invalid answers file");
+        let input = Input::Path(aoc::day_directory(day).join("input"))
+            .read_to_string()
+            .expect("Missing input file");
+
+        let mut group = c.benchmark_group(format!("day{:02}", day));
+        group.sample_size(10);
+        group.warm_up_time(Duration::from_millis(500));
+        if answers.part(1).is_some() {
+            group.bench_function("part1", |b| b.iter(|| solution.part1(&input)));
+        }
+        if answers.part(2).is_some() {
+            group.bench_function("part2", |b| b.iter(|| solution.part2(&input)));
+        }
+        for (_, part, name, solver) in VARIANTS.iter().filter(|(d, _, _, _)| *d == day) {
+            let expected = answers.part(*part).expect("Missing answer of a variant");
+            assert_eq!(
+                solver(&input).expect("Failing variant"),
+                expected,
+                "{}",
+                name
+            );
+            group.bench_function(*name, |b| b.iter(|| solver(&input)));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
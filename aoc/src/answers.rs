@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+/// Expected answers of a day, recorded in the `answers` file of its directory
+/// as `part1: <answer>` and `part2: <answer>` lines.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(day: u8) -> Result<Self, aoc_common::Error> {
+        let path = crate::day_directory(day).join("answers");
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        Ok(aoc_common::Input::Path(path).read_to_string()?.parse()?)
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .try_fold(Answers::default(), |mut answers, (i, line)| {
+                let separator = line.find(':').ok_or(Error::SeparatorNotFound(i + 1))?;
+                let answer = Some(line[separator + 1..].trim().to_owned());
+                match line[..separator].trim() {
+                    "part1" => answers.part1 = answer,
+                    "part2" => answers.part2 = answer,
+                    part => return Err(Error::UnknownPart(i + 1, part.to_owned())),
+                }
+                Ok(answers)
+            })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    SeparatorNotFound(usize),
+    UnknownPart(usize, String),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_answers() {
+        let answers: Answers = "part1: 42\n\npart2: abc,def\n".parse().unwrap();
+        assert_eq!(answers.part(1), Some("42"));
+        assert_eq!(answers.part(2), Some("abc,def"));
+
+        let answers: Answers = "part2: 3".parse().unwrap();
+        assert_eq!(answers.part(1), None);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        assert!(matches!(
+            "part1: 1\npart3: 2".parse::<Answers>(),
+            Err(Error::UnknownPart(2, _))
+        ));
+        assert!(matches!(
+            "part1 1".parse::<Answers>(),
+            Err(Error::SeparatorNotFound(1))
+        ));
+    }
+
+    #[test]
+    fn every_day_has_valid_answers() {
+        for day in 1..=crate::LAST_DAY {
+            assert!(Answers::load(day).is_ok(), "day {}", day);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2020";
+const USER_AGENT: &str = "github.com/Lisible/advent-of-code-2020 by clement.sibille@protonmail.com";
+
+/// Downloads the input of a day into `destination`.
+///
+/// The puzzle inputs never change, so an existing `destination` is treated as
+/// a cached copy and nothing is requested.
+pub fn fetch_input(
+    day: u8,
+    base_url: &str,
+    session: &str,
+    destination: &Path,
+) -> Result<(), Error> {
+    if destination.exists() {
+        return Err(Error::AlreadyCached(destination.to_owned()));
+    }
+
+    let url = format!("{}/day/{}/input", base_url.trim_end_matches('/'), day);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| match e {
+            ureq::Error::Status(status, _) => Error::UnexpectedStatus(url.clone(), status),
+            e => Error::RequestError(Box::new(e)),
+        })?;
+
+    let mut input = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut input)
+        .map_err(Error::ResponseReadError)?;
+    std::fs::write(destination, input).map_err(Error::InputWriteError)
+}
+
+#[derive(Debug)]
+pub enum Error {
+    AlreadyCached(PathBuf),
+    RequestError(Box<ureq::Error>),
+    UnexpectedStatus(String, u16),
+    ResponseReadError(std::io::Error),
+    InputWriteError(std::io::Error),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Answers a single request with `status` and `body`, and returns the request head.
+    fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2020", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn destination(name: &str) -> PathBuf {
+        let destination =
+            std::env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_file(&destination);
+        destination
+    }
+
+    #[test]
+    fn can_fetch_and_cache_input() {
+        let (base_url, server) = serve_once("200 OK", "1721\n979\n");
+        let destination = destination("cache");
+
+        fetch_input(1, &base_url, "secret", &destination).unwrap();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=secret"));
+        assert_eq!(
+            std::fs::read_to_string(&destination).unwrap(),
+            "1721\n979\n"
+        );
+
+        assert!(matches!(
+            fetch_input(1, &base_url, "secret", &destination),
+            Err(Error::AlreadyCached(_))
+        ));
+        std::fs::remove_file(&destination).unwrap();
+    }
+
+    #[test]
+    fn reports_unexpected_status() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+        let destination = destination("status");
+
+        assert!(matches!(
+            fetch_input(2, &base_url, "expired", &destination),
+            Err(Error::UnexpectedStatus(_, 400))
+        ));
+        server.join().unwrap();
+        assert!(!destination.exists());
+    }
+}
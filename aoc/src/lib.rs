@@ -0,0 +1,75 @@
+use aoc_common::Solution;
+use std::path::PathBuf;
+
+mod answers;
+pub mod fetch;
+pub mod report;
+
+pub use answers::Answers;
+
+pub const LAST_DAY: u8 = 25;
+
+pub fn solution(day: u8) -> Option<&'static dyn Solution> {
+    Some(match day {
+        1 => &day1::Day01,
+        2 => &day2::Day02,
+        3 => &day3::Day03,
+        4 => &day4::Day04,
+        5 => &day5::Day05,
+        6 => &day6::Day06,
+        7 => &day7::Day07,
+        8 => &day8::Day08,
+        9 => &day9::Day09,
+        10 => &day10::Day10,
+        11 => &day11::Day11,
+        12 => &day12::Day12,
+        13 => &day13::Day13,
+        14 => &day14::Day14,
+        15 => &day15::Day15,
+        16 => &day16::Day16,
+        17 => &day17::Day17,
+        18 => &day18::Day18,
+        19 => &day19::Day19,
+        20 => &day20::Day20,
+        21 => &day21::Day21,
+        22 => &day22::Day22,
+        23 => &day23::Day23,
+        24 => &day24::Day24,
+        25 => &day25::Day25,
+        _ => return None,
+    })
+}
+
+/// 64-bit FNV-1a hash of an input, to tell which input an answer was computed for.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Directory of a day crate, where its input files are stored.
+pub fn day_directory(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_has_a_solution() {
+        assert!((1..=LAST_DAY).all(|day| solution(day).is_some()));
+        assert!(solution(0).is_none());
+        assert!(solution(26).is_none());
+        assert!(day_directory(1).join("input").is_file());
+    }
+
+    #[test]
+    fn can_hash_input() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+}
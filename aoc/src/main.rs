@@ -0,0 +1,291 @@
+use aoc::Answers;
+use aoc_common::{Args, Context, Error, Input};
+use serde_json::json;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: aoc run <day> <part> [--input FILE] [--format text|json] [--verbose]
+       aoc verify [day]
+       aoc fetch <day> [--session TOKEN] [--base-url URL]
+       aoc report [--format markdown|json] [--criterion-dir DIR]";
+
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    match args.positional() {
+        [command, day, part] if command == "run" => run(parse_day(day)?, parse_part(part)?, &args),
+        [command] if command == "verify" => verify(1..=aoc::LAST_DAY),
+        [command, day] if command == "verify" => {
+            let day = parse_day(day)?;
+            verify(day..=day)
+        }
+        [command, day] if command == "fetch" => fetch(parse_day(day)?, &args),
+        [command] if command == "report" => report(&args),
+        _ => Err(Error::Solver(USAGE.into())),
+    }
+}
+
+/// `--verbose` writes the traces of the solver on stderr.
+fn run(day: u8, part: u8, args: &Args) -> Result<(), Error> {
+    aoc_common::set_tracing(args.flag("verbose"));
+    let format = args.parse_value("format")?.unwrap_or(Format::Text);
+    let input = args
+        .parse_value("input")?
+        .unwrap_or_else(|| default_input(day))
+        .read_to_string()?;
+
+    let (answer, elapsed) = solve(day, part, &input);
+    let answer = answer.context(format!("day {} part {}", day, part))?;
+
+    match format {
+        Format::Text => {
+            println!("Day {} part {}: {}", day, part, answer);
+            println!("Elapsed: {:?}", elapsed);
+        }
+        Format::Json => println!(
+            "{}",
+            json!({
+                "day": day,
+                "part": part,
+                "answer": answer,
+                "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+                "input_hash": aoc::input_hash(&input),
+            })
+        ),
+    }
+
+    Ok(())
+}
+
+/// A day whose answers or input cannot be read is a single failed row.
+fn verify(days: impl Iterator<Item = u8>) -> Result<(), Error> {
+    let mut rows = vec![];
+    for day in days {
+        let loaded = Answers::load(day)
+            .context(format!("day {} answers", day))
+            .and_then(|answers| {
+                let input = default_input(day)
+                    .read_to_string()
+                    .context(format!("day {} input", day))?;
+                Ok((answers, input))
+            });
+        let (answers, input) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                rows.push(Row {
+                    day,
+                    part: None,
+                    expected: "-".to_owned(),
+                    answer: e.to_string(),
+                    status: Status::Fail,
+                    elapsed: None,
+                });
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let expected = answers.part(part);
+            let (status, answer, elapsed) = match expected {
+                None => (Status::Skip, String::new(), None),
+                Some(expected) => match solve(day, part, &input) {
+                    (Ok(answer), elapsed) if answer == expected => {
+                        (Status::Pass, answer, Some(elapsed))
+                    }
+                    (Ok(answer), elapsed) => (Status::Mismatch, answer, Some(elapsed)),
+                    (Err(e), elapsed) => (Status::Fail, e.to_string(), Some(elapsed)),
+                },
+            };
+
+            rows.push(Row {
+                day,
+                part: Some(part),
+                expected: expected.unwrap_or("-").to_owned(),
+                answer,
+                status,
+                elapsed,
+            });
+        }
+    }
+
+    print_table(&rows);
+
+    let failures = rows
+        .iter()
+        .filter(|r| matches!(r.status, Status::Fail | Status::Mismatch))
+        .count();
+    if failures > 0 {
+        return Err(Error::Solver(format!(
+            "{} rows failed verification",
+            failures
+        )));
+    }
+
+    Ok(())
+}
+
+/// Downloads the input of a day into its directory.
+///
+/// The session cookie and the base URL can also be given with the
+/// `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+fn fetch(day: u8, args: &Args) -> Result<(), Error> {
+    let session = match args.value("session")? {
+        Some(session) => session.to_owned(),
+        None => std::env::var("AOC_SESSION").map_err(|_| {
+            Error::Solver("missing session cookie, use `--session` or `AOC_SESSION`".into())
+        })?,
+    };
+    let base_url = match args.value("base-url")? {
+        Some(base_url) => base_url.to_owned(),
+        None => std::env::var("AOC_BASE_URL")
+            .unwrap_or_else(|_| aoc::fetch::DEFAULT_BASE_URL.to_owned()),
+    };
+
+    let destination = aoc::day_directory(day).join("input");
+    aoc::fetch::fetch_input(day, &base_url, &session, &destination)
+        .context(format!("day {} input", day))?;
+    println!("Saved day {} input to {}", day, destination.display());
+    Ok(())
+}
+
+/// Prints the timings of the last `cargo bench -p aoc` run.
+fn report(args: &Args) -> Result<(), Error> {
+    let criterion_directory = args
+        .parse_value("criterion-dir")?
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/criterion"));
+    let timings = aoc::report::load_timings(&criterion_directory)?;
+    if timings.is_empty() {
+        return Err(Error::Solver(format!(
+            "no benchmark results found in `{}`, run `cargo bench -p aoc` first",
+            criterion_directory.display()
+        )));
+    }
+
+    match args.value("format")?.unwrap_or("markdown") {
+        "markdown" => print!("{}", aoc::report::to_markdown(&timings)),
+        "json" => println!("{}", aoc::report::to_json(&timings)),
+        format => return Err(Error::InvalidArgumentValue("format".into(), format.into())),
+    }
+
+    Ok(())
+}
+
+fn solve(day: u8, part: u8, input: &str) -> (Result<String, Error>, Duration) {
+    let solution = aoc::solution(day).expect("Days are validated when parsing arguments");
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part1(input),
+        _ => solution.part2(input),
+    };
+
+    (answer, start.elapsed())
+}
+
+fn default_input(day: u8) -> Input {
+    Input::Path(aoc::day_directory(day).join("input"))
+}
+
+/// Output of `aoc run`, given with `--format`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Status {
+    Pass,
+    Mismatch,
+    Fail,
+    Skip,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Mismatch => "mismatch",
+            Status::Fail => "fail",
+            Status::Skip => "skip",
+        }
+    }
+}
+
+struct Row {
+    day: u8,
+    /// `None` when the day could not be verified at all.
+    part: Option<u8>,
+    expected: String,
+    answer: String,
+    status: Status,
+    elapsed: Option<Duration>,
+}
+
+fn print_table(rows: &[Row]) {
+    let expected_width = rows.iter().fold(8, |acc, r| acc.max(r.expected.len()));
+    let answer_width = rows.iter().fold(6, |acc, r| acc.max(r.answer.len()));
+
+    println!(
+        "{:>3}  {:>4}  {:<8}  {:<ew$}  {:<aw$}  Elapsed",
+        "Day",
+        "Part",
+        "Status",
+        "Expected",
+        "Answer",
+        ew = expected_width,
+        aw = answer_width
+    );
+    for row in rows {
+        println!(
+            "{:>3}  {:>4}  {:<8}  {:<ew$}  {:<aw$}  {}",
+            row.day,
+            row.part
+                .map_or_else(|| "-".to_owned(), |part| part.to_string()),
+            row.status.label(),
+            row.expected,
+            row.answer,
+            row.elapsed.map(|e| format!("{:?}", e)).unwrap_or_default(),
+            ew = expected_width,
+            aw = answer_width
+        );
+    }
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} passed, {} mismatched, {} failed, {} skipped",
+        count(Status::Pass),
+        count(Status::Mismatch),
+        count(Status::Fail),
+        count(Status::Skip)
+    );
+}
+
+fn parse_day(day: &str) -> Result<u8, Error> {
+    day.parse()
+        .ok()
+        .filter(|day| (1..=aoc::LAST_DAY).contains(day))
+        .ok_or_else(|| Error::Solver(format!("invalid day `{}`, expected 1 to 25", day)))
+}
+
+fn parse_part(part: &str) -> Result<u8, Error> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(Error::Solver(format!(
+            "invalid part `{}`, expected 1 or 2",
+            part
+        ))),
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::{Context, Error};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::time::Duration;
+
+/// Timing of a solver measured by the `solutions` benchmark.
+#[derive(Debug, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub solver: String,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Timing {
+    fn from_estimates(day: u8, solver: &str, estimates: &str) -> Result<Self, Error> {
+        let estimates: Value = serde_json::from_str(estimates).map_err(Error::solver)?;
+        let estimate = |name: &str| {
+            estimates[name]["point_estimate"]
+                .as_f64()
+                .map(|nanos| Duration::from_nanos(nanos as u64))
+                .ok_or_else(|| Error::Solver(format!("missing {} estimate", name)))
+        };
+
+        Ok(Timing {
+            day,
+            solver: solver.to_owned(),
+            mean: estimate("mean")?,
+            std_dev: estimate("std_dev")?,
+        })
+    }
+}
+
+/// Reads the timings saved by criterion in `criterion_directory`, sorted by day and solver.
+pub fn load_timings(criterion_directory: &Path) -> Result<Vec<Timing>, Error> {
+    let mut timings = vec![];
+    for day in 1..=crate::LAST_DAY {
+        let day_directory = criterion_directory.join(format!("day{:02}", day));
+        if !day_directory.is_dir() {
+            continue;
+        }
+
+        let entries = day_directory
+            .read_dir()
+            .map_err(Error::InputReadError)
+            .context(day_directory.display().to_string())?;
+        for entry in entries {
+            let solver_directory = entry.map_err(Error::InputReadError)?.path();
+            let estimates = solver_directory.join("new").join("estimates.json");
+            if !estimates.is_file() {
+                continue;
+            }
+
+            let solver = solver_directory
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let estimates = aoc_common::Input::Path(estimates).read_to_string()?;
+            timings.push(
+                Timing::from_estimates(day, &solver, &estimates)
+                    .context(format!("day {} {}", day, solver))?,
+            );
+        }
+    }
+
+    timings.sort_by(|a, b| (a.day, &a.solver).cmp(&(b.day, &b.solver)));
+    Ok(timings)
+}
+
+pub fn to_markdown(timings: &[Timing]) -> String {
+    timings.iter().fold(
+        String::from("| Day | Solver | Mean | Std. dev. |\n|----:|--------|-----:|----------:|\n"),
+        |acc, t| {
+            acc + &format!(
+                "| {} | {} | {:.2?} | {:.2?} |\n",
+                t.day, t.solver, t.mean, t.std_dev
+            )
+        },
+    )
+}
+
+pub fn to_json(timings: &[Timing]) -> String {
+    let timings: Vec<Value> = timings
+        .iter()
+        .map(|t| {
+            json!({
+                "day": t.day,
+                "solver": t.solver,
+                "mean_ns": t.mean.as_nanos() as u64,
+                "std_dev_ns": t.std_dev.as_nanos() as u64,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&timings).expect("Timings are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_read_criterion_estimates() {
+        let estimates = r#"{
+            "mean": {"confidence_interval": {}, "point_estimate": 1500.7, "standard_error": 3.0},
+            "std_dev": {"confidence_interval": {}, "point_estimate": 20.0, "standard_error": 1.0}
+        }"#;
+        let timing = Timing::from_estimates(8, "part2", estimates).unwrap();
+        assert_eq!(timing.mean, Duration::from_nanos(1500));
+        assert_eq!(timing.std_dev, Duration::from_nanos(20));
+
+        let markdown = to_markdown(&[timing]);
+        assert!(markdown.ends_with("| 8 | part2 | 1.50µs | 20.00ns |\n"));
+    }
+}
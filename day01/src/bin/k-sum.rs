@@ -0,0 +1,43 @@
+use aoc_common::{Args, Error};
+use day1::{find_all_k_sums, find_k_sum, parse_values, product, Entry};
+
+/// Finds `--k` entries of the expense report summing to `--target`, 2 entries
+/// summing to 2020 by default. `--all` prints every solution and `--type` sets
+/// the integer type of the entries: `u32` (default), `u64`, `i64` or `u128`.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let input = args.input()?.read_to_string()?;
+    match args.value("type")?.unwrap_or("u32") {
+        "u32" => print_k_sums::<u32>(&args, &input),
+        "u64" => print_k_sums::<u64>(&args, &input),
+        "i64" => print_k_sums::<i64>(&args, &input),
+        "u128" => print_k_sums::<u128>(&args, &input),
+        entry_type => Err(Error::InvalidArgumentValue(
+            "type".into(),
+            entry_type.into(),
+        )),
+    }
+}
+
+fn print_k_sums<T: Entry>(args: &Args, input: &str) -> Result<(), Error> {
+    let values: Vec<T> = parse_values(input)?;
+    let k = args.parse_value("k")?.unwrap_or(2);
+    let target = args.parse_value("target")?.unwrap_or_else(|| T::from(2020));
+
+    let solutions = if args.flag("all") {
+        find_all_k_sums(&values, k, target)?
+    } else {
+        find_k_sum(&values, k, target)?.into_iter().collect()
+    };
+    if solutions.is_empty() {
+        return Err(Error::Solver(format!("No {} values sum to {}", k, target)));
+    }
+
+    for solution in solutions {
+        let terms: Vec<String> = solution.iter().map(T::to_string).collect();
+        let product = product(&solution).map_or_else(|_| "overflow".into(), |p| p.to_string());
+        println!("{} = {}, product: {}", terms.join(" + "), target, product);
+    }
+
+    Ok(())
+}
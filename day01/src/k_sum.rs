@@ -0,0 +1,152 @@
+use crate::{Entry, Error};
+use std::collections::HashMap;
+
+/// Finds `k` entries of `values`, at distinct positions, that sum to `target`.
+///
+/// The entries are returned in the order they appear in `values`. When some
+/// entries are negative, combinations whose partial sums overflow are skipped,
+/// and [`Error::Overflow`] is returned if no solution is found without them.
+pub fn find_k_sum<T: Entry>(values: &[T], k: usize, target: T) -> Result<Option<Vec<T>>, Error> {
+    Ok(k_sums(values, k, target, true)?.into_iter().next())
+}
+
+/// Every set of `k` entries of `values`, at distinct positions, that sum to `target`.
+///
+/// [`Error::Overflow`] is returned when a partial sum overflows, as some
+/// solutions could be missing.
+pub fn find_all_k_sums<T: Entry>(values: &[T], k: usize, target: T) -> Result<Vec<Vec<T>>, Error> {
+    k_sums(values, k, target, false)
+}
+
+/// Product of `k` entries summing to `target`.
+pub fn k_sum_product<T: Entry>(values: &[T], k: usize, target: T) -> Result<Option<T>, Error> {
+    find_k_sum(values, k, target)?
+        .map(|entries| product(&entries))
+        .transpose()
+}
+
+pub fn product<T: Entry>(entries: &[T]) -> Result<T, Error> {
+    entries
+        .iter()
+        .try_fold(T::ONE, |acc, &entry| acc.checked_mul(entry))
+        .ok_or(Error::Overflow)
+}
+
+/// Meet in the middle: the sums of the combinations of the first `k / 2` entries
+/// are indexed, then the remaining entries are looked up from the combinations of
+/// the other `k - k / 2` entries.
+///
+/// A solution is only matched with its left half ending before its right half
+/// begins, so each set of positions is found once.
+fn k_sums<T: Entry>(
+    values: &[T],
+    k: usize,
+    target: T,
+    first_only: bool,
+) -> Result<Vec<Vec<T>>, Error> {
+    if k > values.len() {
+        return Ok(vec![]);
+    }
+
+    // Without negative entries, sums only grow, so the ones past the target
+    // are dropped, overflowing ones included.
+    let max_sum = if values.iter().all(|&value| value >= T::ZERO) {
+        Some(target)
+    } else {
+        None
+    };
+
+    let mut overflowed = false;
+    let mut left_halves: HashMap<T, Vec<Vec<usize>>> = HashMap::new();
+    for (sum, combination) in combinations(values, k / 2, max_sum, &mut overflowed) {
+        left_halves.entry(sum).or_default().push(combination);
+    }
+
+    let mut solutions = vec![];
+    for (sum, right_half) in combinations(values, k - k / 2, max_sum, &mut overflowed) {
+        // A left half sums to a `T`, so it can't match an unrepresentable difference.
+        let left_halves = target
+            .checked_sub(sum)
+            .and_then(|left_sum| left_halves.get(&left_sum));
+        let first_right = right_half.first().copied().unwrap_or(values.len());
+        for left_half in left_halves.into_iter().flatten() {
+            if left_half.last().is_some_and(|&last| last >= first_right) {
+                continue;
+            }
+
+            solutions.push(
+                left_half
+                    .iter()
+                    .chain(&right_half)
+                    .map(|&i| values[i])
+                    .collect(),
+            );
+            if first_only {
+                return Ok(solutions);
+            }
+        }
+    }
+
+    if overflowed {
+        return Err(Error::Overflow);
+    }
+
+    solutions.sort();
+    Ok(solutions)
+}
+
+/// Positions of every combination of `size` entries along with their sum.
+///
+/// Combinations exceeding `max_sum` are skipped, as well as the ones whose sum
+/// overflows, `overflowed` being set for the latter when there is no `max_sum`.
+fn combinations<T: Entry>(
+    values: &[T],
+    size: usize,
+    max_sum: Option<T>,
+    overflowed: &mut bool,
+) -> Vec<(T, Vec<usize>)> {
+    struct Search<'a, T> {
+        values: &'a [T],
+        size: usize,
+        max_sum: Option<T>,
+        overflowed: &'a mut bool,
+        combination: Vec<usize>,
+        combinations: Vec<(T, Vec<usize>)>,
+    }
+
+    impl<T: Entry> Search<'_, T> {
+        fn extend(&mut self, start: usize, sum: T) {
+            if self.combination.len() == self.size {
+                self.combinations.push((sum, self.combination.clone()));
+                return;
+            }
+
+            for i in start..self.values.len() {
+                let sum = match (sum.checked_add(self.values[i]), self.max_sum) {
+                    (Some(sum), Some(max_sum)) if sum > max_sum => continue,
+                    (Some(sum), _) => sum,
+                    (None, Some(_)) => continue,
+                    (None, None) => {
+                        *self.overflowed = true;
+                        continue;
+                    }
+                };
+
+                self.combination.push(i);
+                self.extend(i + 1, sum);
+                self.combination.pop();
+            }
+        }
+    }
+
+    let mut search = Search {
+        values,
+        size,
+        max_sum,
+        overflowed,
+        combination: vec![],
+        combinations: vec![],
+    };
+    search.extend(0, T::ZERO);
+    search.combinations
+}
@@ -0,0 +1,190 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::num::ParseIntError;
+
+mod entry;
+mod k_sum;
+mod part1;
+mod part1_naive;
+mod part2;
+
+pub use entry::Entry;
+pub use k_sum::{find_all_k_sums, find_k_sum, k_sum_product, product};
+pub use part1::{find_pair, part1, Pair};
+pub use part1_naive::{find_pair_naive, part1_naive};
+pub use part2::part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+/// Parses the entries of an expense report, one per line.
+pub fn parse_values<T: Entry>(input: &str) -> Result<Vec<T>, ParseError<ParseIntError>> {
+    parse_lines(input.trim_end(), |line| {
+        line.parse().map_err(|e| ParseError::new(line, line, e))
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Overflow,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 514579);
+    }
+
+    #[test]
+    fn can_solve_part1_naive_example() {
+        assert_eq!(
+            part1_naive(include_str!("../example_input")).unwrap(),
+            514579
+        );
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 241861950);
+    }
+
+    #[test]
+    fn can_find_pairs() {
+        let values: Vec<u32> = parse_values(include_str!("../example_input")).unwrap();
+        let pair = Pair {
+            first: 299,
+            second: 1721,
+            product: 514579,
+        };
+        assert_eq!(find_pair(&values, 2020), Ok(Some(pair)));
+        assert_eq!(find_pair(&values, 1), Ok(None));
+        assert_eq!(find_pair::<u32>(&[], 0), Ok(None));
+        assert_eq!(find_pair(&[1010u32], 2020), Ok(None));
+        assert_eq!(
+            find_pair(&[u32::MAX, 7, u32::MAX - 7], u32::MAX),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            find_pair(&[i64::MAX, 5, i64::MIN, -3], 2).unwrap().unwrap(),
+            Pair::new(-3, 5).unwrap()
+        );
+    }
+
+    /// Whether a pair was found, its product possibly overflowing.
+    fn is_found<T>(result: &Result<Option<Pair<T>>, Error>) -> bool {
+        !matches!(result, Ok(None))
+    }
+
+    fn is_pair_of<T: Entry>(pair: &Pair<T>, values: &[T], target: T) -> bool {
+        let first = values.iter().position(|&v| v == pair.first);
+        let second = values.iter().rposition(|&v| v == pair.second);
+        pair.first.checked_add(pair.second) == Some(target)
+            && matches!((first, second), (Some(i), Some(j)) if i != j)
+    }
+
+    proptest! {
+        #[test]
+        fn find_pair_agrees_with_naive(
+            values in vec(-2000i64..2000, 0..40),
+            target in -4000i64..4000,
+        ) {
+            let pair = find_pair(&values, target);
+            prop_assert_eq!(is_found(&pair), is_found(&find_pair_naive(&values, target)));
+            if let Ok(Some(pair)) = pair {
+                prop_assert!(is_pair_of(&pair, &values, target));
+            }
+        }
+
+        #[test]
+        fn find_pair_handles_any_entries(values in vec(any::<i64>(), 0..40), i: usize, j: usize) {
+            let target = match values.len() {
+                0 | 1 => 0,
+                len => values[i % len].wrapping_add(values[(i + 1 + j % (len - 1)) % len]),
+            };
+            let pair = find_pair(&values, target);
+            prop_assert_eq!(is_found(&pair), is_found(&find_pair_naive(&values, target)));
+            if let Ok(Some(pair)) = pair {
+                prop_assert!(is_pair_of(&pair, &values, target));
+            }
+        }
+    }
+
+    #[test]
+    fn can_find_k_sums() {
+        let values: Vec<u32> = parse_values(include_str!("../example_input")).unwrap();
+        assert_eq!(find_k_sum(&values, 2, 2020), Ok(Some(vec![1721, 299])));
+        assert_eq!(find_k_sum(&values, 3, 2020), Ok(Some(vec![979, 366, 675])));
+        assert_eq!(find_k_sum(&values, 4, 2020), Ok(None));
+        assert_eq!(find_k_sum(&values, 1, 366), Ok(Some(vec![366])));
+        assert_eq!(find_k_sum(&values, 7, 0), Ok(None));
+        assert_eq!(find_k_sum(&values, 0, 0), Ok(Some(vec![])));
+    }
+
+    #[test]
+    fn can_find_all_k_sums() {
+        let values = [1, 2, 3, 4, 5];
+        assert_eq!(
+            find_all_k_sums(&values, 3, 9).unwrap(),
+            [[1, 3, 5], [2, 3, 4]]
+        );
+        assert_eq!(find_all_k_sums(&values, 4, 10).unwrap(), [[1, 2, 3, 4]]);
+        assert_eq!(find_all_k_sums(&[5, 5, 5], 2, 10).unwrap().len(), 3);
+        assert_eq!(find_all_k_sums(&[u32::MAX, 1, 0], 2, 1).unwrap(), [[1, 0]]);
+    }
+
+    #[test]
+    fn supports_other_integer_types() {
+        let values: Vec<i64> = parse_values("-5\n10\n3\n7").unwrap();
+        assert_eq!(find_k_sum(&values, 2, 5), Ok(Some(vec![-5, 10])));
+        assert_eq!(k_sum_product(&values, 3, 5), Ok(Some(-105)));
+
+        let values = [u128::MAX - 1, 2, 1];
+        assert_eq!(
+            find_k_sum(&values, 2, u128::MAX),
+            Ok(Some(vec![u128::MAX - 1, 1]))
+        );
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let values: Vec<u32> = parse_values("70000\n70000").unwrap();
+        assert_eq!(k_sum_product(&values, 2, 140000), Err(Error::Overflow));
+        let values: Vec<u64> = parse_values("70000\n70000").unwrap();
+        assert_eq!(k_sum_product(&values, 2, 140000), Ok(Some(4900000000)));
+
+        let values = [i64::MAX, i64::MAX, -1];
+        assert_eq!(
+            find_k_sum(&values, 2, i64::MAX - 1),
+            Ok(Some(vec![i64::MAX, -1]))
+        );
+        assert_eq!(find_all_k_sums(&values, 3, 0), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = parse_values::<u32>("1721\n979\n36x6\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (3, 1, "36x6")
+        );
+    }
+}
@@ -1,27 +1,54 @@
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut values: Vec<u32> = std::fs::read_to_string("../input")?
-        .trim()
-        .split("\n")
-        .map(|v| v.parse::<u32>().unwrap())
-        .collect();
-    values.sort();
+use crate::{parse_values, Entry, Error};
+
+/// Two entries summing to a target, along with their product.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pair<T> {
+    pub first: T,
+    pub second: T,
+    pub product: T,
+}
+
+impl<T: Entry> Pair<T> {
+    pub(crate) fn new(first: T, second: T) -> Result<Self, Error> {
+        Ok(Pair {
+            first,
+            second,
+            product: first.checked_mul(second).ok_or(Error::Overflow)?,
+        })
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, aoc_common::Error> {
+    let values: Vec<u32> = parse_values(input)?;
+    let pair = find_pair(&values, 2020)?
+        .ok_or_else(|| aoc_common::Error::Solver("No pair of values sums to 2020".into()))?;
+    Ok(pair.product)
+}
+
+/// Two pointers search over the sorted entries, `first` being the smallest
+/// entry of the pair.
+pub fn find_pair<T: Entry>(values: &[T], target: T) -> Result<Option<Pair<T>>, Error> {
+    let mut values = values.to_vec();
+    values.sort_unstable();
 
     let mut left = 0;
-    let mut right = values.len() - 1;
-    loop {
+    let mut right = match values.len() {
+        0 => return Ok(None),
+        len => len - 1,
+    };
+    while left < right {
         let first_value = values[left];
         let second_value = values[right];
 
-        let sum = first_value + second_value;
-        if sum == 2020 {
-            println!("{} * {} = {}", first_value, second_value, sum);
-            break;
-        } else if sum > 2020 {
-            right -= 1;
-        } else {
-            left += 1
+        match first_value.checked_add(second_value) {
+            Some(sum) if sum == target => return Pair::new(first_value, second_value).map(Some),
+            Some(sum) if sum < target => left += 1,
+            Some(_) => right -= 1,
+            // Both entries have the sign of the overflow.
+            None if second_value > T::ZERO => right -= 1,
+            None => left += 1,
         }
     }
 
-    Ok(())
+    Ok(None)
 }
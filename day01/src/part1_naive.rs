@@ -0,0 +1,21 @@
+use crate::{parse_values, Entry, Error, Pair};
+
+pub fn part1_naive(input: &str) -> Result<u32, aoc_common::Error> {
+    let values: Vec<u32> = parse_values(input)?;
+    let pair = find_pair_naive(&values, 2020)?
+        .ok_or_else(|| aoc_common::Error::Solver("No pair of values sums to 2020".into()))?;
+    Ok(pair.product)
+}
+
+/// Tries every pair of entries, they are returned in their input order.
+pub fn find_pair_naive<T: Entry>(values: &[T], target: T) -> Result<Option<Pair<T>>, Error> {
+    for i in 0..values.len() {
+        for j in i + 1..values.len() {
+            if values[i].checked_add(values[j]) == Some(target) {
+                return Pair::new(values[i], values[j]).map(Some);
+            }
+        }
+    }
+
+    Ok(None)
+}
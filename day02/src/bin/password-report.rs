@@ -0,0 +1,94 @@
+use aoc_common::{Args, Error};
+use day2::{
+    CountRange, Database, ForbiddenSubstrings, Matches, MinEntropy, Mode, PasswordPolicy,
+    PositionXor,
+};
+use regex::Regex;
+
+/// Prints which policies the password of each line of the database fails.
+///
+/// `--policy count-range|position-xor` selects the puzzle policies, `count-range`
+/// by default. `--regex PATTERN`, `--min-entropy BITS` and `--forbid SUBSTRING`
+/// add extra policies, `--regex` and `--forbid` can be repeated.
+///
+/// A malformed line is an error, unless `--lenient` is given: malformed lines
+/// are then skipped and counted by kind of error.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let policies = policies(&args)?;
+    let input = args.input()?.read_to_string()?;
+    let mode = if args.flag("lenient") {
+        Mode::Lenient
+    } else {
+        Mode::Strict
+    };
+    let database = Database::parse(&input, mode)?;
+
+    let mut valid_count = 0;
+    for (line, entry) in &database.entries {
+        let failed: Vec<&str> = policies
+            .iter()
+            .filter(|policy| !policy.is_satisfied(entry))
+            .map(|policy| policy.name())
+            .collect();
+        if failed.is_empty() {
+            valid_count += 1;
+            println!("line {}: `{}` ok", line, entry.password);
+        } else {
+            println!(
+                "line {}: `{}` fails {}",
+                line,
+                entry.password,
+                failed.join(", ")
+            );
+        }
+    }
+
+    println!(
+        "{} of {} passwords satisfy every policy",
+        valid_count,
+        database.entries.len()
+    );
+    if !database.skipped.is_empty() {
+        println!("{} malformed lines skipped:", database.skipped.len());
+        for (error, count) in database.skipped_summary() {
+            println!("  {:?} ({}): {}", error, error, count);
+        }
+    }
+
+    Ok(())
+}
+
+fn policies(args: &Args) -> Result<Vec<Box<dyn PasswordPolicy>>, Error> {
+    let mut policies: Vec<Box<dyn PasswordPolicy>> = vec![];
+
+    let mut names = args.values("policy")?;
+    if names.is_empty() {
+        names.push("count-range");
+    }
+    for name in names {
+        policies.push(match name {
+            "count-range" => Box::new(CountRange),
+            "position-xor" => Box::new(PositionXor),
+            _ => return Err(Error::InvalidArgumentValue("policy".into(), name.into())),
+        });
+    }
+
+    for pattern in args.values("regex")? {
+        let regex = Regex::new(pattern)
+            .map_err(|_| Error::InvalidArgumentValue("regex".into(), pattern.into()))?;
+        policies.push(Box::new(Matches(regex)));
+    }
+
+    if let Some(bits) = args.parse_value("min-entropy")? {
+        policies.push(Box::new(MinEntropy(bits)));
+    }
+
+    let forbidden = args.values("forbid")?;
+    if !forbidden.is_empty() {
+        let forbidden = forbidden.into_iter().map(String::from).collect();
+        policies.push(Box::new(ForbiddenSubstrings(forbidden)));
+    }
+
+    Ok(policies)
+}
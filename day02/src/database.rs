@@ -0,0 +1,52 @@
+use crate::{ParseError, PasswordEntry, PasswordPolicy};
+use std::collections::BTreeMap;
+
+/// How malformed lines of the database are handled.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mode {
+    /// The first malformed line is an error.
+    Strict,
+    /// Malformed lines are skipped and recorded.
+    Lenient,
+}
+
+/// Parsed password database, lines numbered from 1.
+#[derive(Debug, Default)]
+pub struct Database<'a> {
+    pub entries: Vec<(usize, PasswordEntry<'a>)>,
+    pub skipped: Vec<(usize, ParseError)>,
+}
+
+impl<'a> Database<'a> {
+    pub fn parse(input: &'a str, mode: Mode) -> Result<Self, aoc_common::ParseError<ParseError>> {
+        input
+            .lines()
+            .enumerate()
+            .try_fold(Database::default(), |mut acc, (i, line)| {
+                match (PasswordEntry::parse(line), mode) {
+                    (Ok(entry), _) => acc.entries.push((i + 1, entry)),
+                    (Err(e), Mode::Lenient) => acc.skipped.push((i + 1, e.kind)),
+                    (Err(e), Mode::Strict) => return Err(e.at_line(i + 1)),
+                }
+
+                Ok(acc)
+            })
+    }
+
+    pub fn count_valid(&self, policy: &dyn PasswordPolicy) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| policy.is_satisfied(entry))
+            .count()
+    }
+
+    /// Number of skipped lines for each kind of error.
+    pub fn skipped_summary(&self) -> BTreeMap<ParseError, usize> {
+        self.skipped
+            .iter()
+            .fold(BTreeMap::new(), |mut acc, &(_, error)| {
+                *acc.entry(error).or_insert(0) += 1;
+                acc
+            })
+    }
+}
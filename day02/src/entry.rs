@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+
+/// Line of the password database: `1-3 a: abcde`.
+///
+/// How the two numbers and the character are interpreted depends on the policy.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PasswordEntry<'a> {
+    pub first: usize,
+    pub second: usize,
+    pub character: char,
+    pub password: &'a str,
+}
+
+impl<'a> PasswordEntry<'a> {
+    /// Parses a line, the errors being located on the offending token.
+    pub fn parse(line: &'a str) -> Result<Self, aoc_common::ParseError<ParseError>> {
+        let error = |token: &str, kind| aoc_common::ParseError::new(line, token, kind);
+        let end = &line[line.len()..];
+        let mut split_line = line.split(' ');
+
+        let (first, second) = parse_numbers(
+            line,
+            split_line
+                .next()
+                .ok_or_else(|| error(end, ParseError::MissingRangeString))?,
+        )?;
+
+        let character_string = split_line
+            .next()
+            .ok_or_else(|| error(end, ParseError::MissingCharacterString))?;
+        let character_string = character_string.strip_suffix(':').ok_or_else(|| {
+            error(
+                &character_string[character_string.len()..],
+                ParseError::MissingColon,
+            )
+        })?;
+        let mut characters = character_string.chars();
+        let character = match (characters.next(), characters.next()) {
+            (Some(character), None) => character,
+            (None, _) => return Err(error(character_string, ParseError::MissingCharacter)),
+            (Some(_), Some(_)) => {
+                return Err(error(character_string, ParseError::InvalidCharacter))
+            }
+        };
+
+        let password = split_line
+            .next()
+            .filter(|password| !password.is_empty())
+            .ok_or_else(|| error(end, ParseError::MissingPasswordString))?;
+
+        if let Some(text) = split_line.next() {
+            return Err(error(text, ParseError::UnexpectedText));
+        }
+
+        Ok(PasswordEntry {
+            first,
+            second,
+            character,
+            password,
+        })
+    }
+}
+
+/// `first-second`, `numbers` being a token of `line`.
+fn parse_numbers(
+    line: &str,
+    numbers: &str,
+) -> Result<(usize, usize), aoc_common::ParseError<ParseError>> {
+    let error = |token: &str, kind| aoc_common::ParseError::new(line, token, kind);
+    let end = &numbers[numbers.len()..];
+    let parse_number = |number: &str| {
+        number
+            .parse()
+            .map_err(|_| error(number, ParseError::ParseRangeBoundError))
+    };
+    let mut split_numbers = numbers.split('-');
+    let first = parse_number(
+        split_numbers
+            .next()
+            .ok_or_else(|| error(end, ParseError::MissingMinBound))?,
+    )?;
+    let second = parse_number(
+        split_numbers
+            .next()
+            .ok_or_else(|| error(end, ParseError::MissingMaxBound))?,
+    )?;
+    if let Some(bound) = split_numbers.next() {
+        return Err(error(bound, ParseError::UnexpectedRangeBound));
+    }
+
+    Ok((first, second))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseError {
+    ParseRangeBoundError,
+    MissingRangeString,
+    MissingMinBound,
+    MissingMaxBound,
+    UnexpectedRangeBound,
+    MissingCharacter,
+    InvalidCharacter,
+    MissingCharacterString,
+    MissingColon,
+    MissingPasswordString,
+    UnexpectedText,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseError::ParseRangeBoundError => "a number of the policy is invalid",
+            ParseError::MissingRangeString => "the policy numbers are missing",
+            ParseError::MissingMinBound => "the first number of the policy is missing",
+            ParseError::MissingMaxBound => "the second number of the policy is missing",
+            ParseError::UnexpectedRangeBound => "the policy has more than two numbers",
+            ParseError::MissingCharacter => "the policy character is empty",
+            ParseError::InvalidCharacter => "the policy character is more than one character",
+            ParseError::MissingCharacterString => "the policy character is missing",
+            ParseError::MissingColon => "the policy character is not followed by `:`",
+            ParseError::MissingPasswordString => "the password is missing",
+            ParseError::UnexpectedText => "the password is followed by more text",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ParseError {}
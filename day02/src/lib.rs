@@ -0,0 +1,107 @@
+use aoc_common::{Error, Solution};
+
+mod database;
+mod entry;
+mod part1;
+mod part2;
+mod policy;
+
+pub use database::{Database, Mode};
+pub use entry::{ParseError, PasswordEntry};
+pub use part1::part1;
+pub use part2::part2;
+pub use policy::{
+    entropy, CountRange, ForbiddenSubstrings, Matches, MinEntropy, PasswordPolicy, PositionXor,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn part1(&self, input: &str) -> Result<String, Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 2);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 1);
+    }
+
+    #[test]
+    fn strict_mode_reports_malformed_lines() {
+        let error = Database::parse("1-3 a: abcde\n1-3 b:\n", Mode::Strict).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 7, ParseError::MissingPasswordString)
+        );
+        let error = part1("1-3 a: abcde\n1-x a: a").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 3: ParseRangeBoundError at `x`"));
+        for (line, column, text, kind) in [
+            ("1-3-5 a: x", 5, "5", ParseError::UnexpectedRangeBound),
+            ("1-3 ab: x", 5, "ab", ParseError::InvalidCharacter),
+            ("1-3 a x", 6, "", ParseError::MissingColon),
+            ("1-3 a: x y", 10, "y", ParseError::UnexpectedText),
+        ] {
+            let error = Database::parse(line, Mode::Strict).unwrap_err();
+            assert_eq!(
+                (error.column, error.text.as_str(), error.kind),
+                (column, text, kind)
+            );
+        }
+    }
+
+    #[test]
+    fn lenient_mode_skips_malformed_lines() {
+        let input = "1-3 a: abcde\n1-3 b\n1 b: bb\n\n2-9 c: ccccccccc\n1-3 c";
+        let database = Database::parse(input, Mode::Lenient).unwrap();
+        assert_eq!(database.count_valid(&CountRange), 2);
+        assert_eq!(
+            database.skipped,
+            [
+                (2, ParseError::MissingColon),
+                (3, ParseError::MissingMaxBound),
+                (4, ParseError::ParseRangeBoundError),
+                (6, ParseError::MissingColon)
+            ]
+        );
+        let summary = database.skipped_summary();
+        assert_eq!(summary[&ParseError::MissingColon], 2);
+        assert_eq!(summary.len(), 3);
+    }
+
+    #[test]
+    fn can_check_extra_policies() {
+        let entry = PasswordEntry::parse("1-3 a: abcabc").unwrap();
+        assert!(Matches(regex::Regex::new("^a.*c$").unwrap()).is_satisfied(&entry));
+        assert!(!Matches(regex::Regex::new("^b").unwrap()).is_satisfied(&entry));
+        assert!(ForbiddenSubstrings(vec!["cba".into()]).is_satisfied(&entry));
+        assert!(!ForbiddenSubstrings(vec!["xyz".into(), "bca".into()]).is_satisfied(&entry));
+        assert!((entropy("abcabc") - 6.0 * 3f64.log2()).abs() < 1e-9);
+        assert_eq!(entropy("aaaa"), 0.0);
+        assert!(MinEntropy(9.0).is_satisfied(&entry));
+        assert!(!MinEntropy(10.0).is_satisfied(&entry));
+    }
+
+    #[test]
+    fn positions_outside_the_password_are_not_matched() {
+        let entry = PasswordEntry::parse("0-4 a: ab").unwrap();
+        assert!(!PositionXor.is_satisfied(&entry));
+        let entry = PasswordEntry::parse("1-9 a: ab").unwrap();
+        assert!(PositionXor.is_satisfied(&entry));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::PasswordEntry;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Rule a password of the database has to follow.
+pub trait PasswordPolicy {
+    fn name(&self) -> &'static str;
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool;
+}
+
+/// The character appears between `first` and `second` times.
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn name(&self) -> &'static str {
+        "count-range"
+    }
+
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool {
+        let count = entry
+            .password
+            .chars()
+            .filter(|&c| c == entry.character)
+            .count();
+        (entry.first..=entry.second).contains(&count)
+    }
+}
+
+/// The character is at exactly one of the positions `first` and `second`,
+/// positions starting at 1.
+pub struct PositionXor;
+
+impl PasswordPolicy for PositionXor {
+    fn name(&self) -> &'static str {
+        "position-xor"
+    }
+
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool {
+        let has_character_at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|i| entry.password.chars().nth(i))
+                == Some(entry.character)
+        };
+        has_character_at(entry.first) ^ has_character_at(entry.second)
+    }
+}
+
+/// The password matches a regular expression.
+pub struct Matches(pub Regex);
+
+impl PasswordPolicy for Matches {
+    fn name(&self) -> &'static str {
+        "regex"
+    }
+
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool {
+        self.0.is_match(entry.password)
+    }
+}
+
+/// The password carries at least this many bits of entropy, see [`entropy`].
+pub struct MinEntropy(pub f64);
+
+impl PasswordPolicy for MinEntropy {
+    fn name(&self) -> &'static str {
+        "min-entropy"
+    }
+
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool {
+        entropy(entry.password) >= self.0
+    }
+}
+
+/// The password contains none of these substrings.
+pub struct ForbiddenSubstrings(pub Vec<String>);
+
+impl PasswordPolicy for ForbiddenSubstrings {
+    fn name(&self) -> &'static str {
+        "forbidden-substring"
+    }
+
+    fn is_satisfied(&self, entry: &PasswordEntry) -> bool {
+        !self.0.iter().any(|s| entry.password.contains(s.as_str()))
+    }
+}
+
+/// Length of the password times the Shannon entropy of its character frequencies,
+/// in bits.
+pub fn entropy(password: &str) -> f64 {
+    let counts = password.chars().fold(HashMap::new(), |mut acc, c| {
+        *acc.entry(c).or_insert(0) += 1;
+        acc
+    });
+    let length = password.chars().count() as f64;
+    counts
+        .values()
+        .map(|&count| -(count as f64) * (count as f64 / length).log2())
+        .sum()
+}
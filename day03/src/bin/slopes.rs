@@ -0,0 +1,55 @@
+use aoc_common::{Args, Error};
+use day3::{Objective, Slope, TreeMap, PART2_SLOPES};
+
+/// Counts the trees hit on the map for each `--slope RIGHT,DOWN`, the slopes of
+/// part 2 by default.
+///
+/// `--best fewest|most` instead searches the slope hitting the fewest or most
+/// trees, among the ones moving at most `--bound` cells at each step (10 by default).
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let map: TreeMap = args.input()?.read_to_string()?.parse()?;
+
+    if let Some(objective) = args.value("best")? {
+        let objective = match objective {
+            "fewest" => Objective::FewestTrees,
+            "most" => Objective::MostTrees,
+            _ => return Err(Error::InvalidArgumentValue("best".into(), objective.into())),
+        };
+        let bound = args.parse_value("bound")?.unwrap_or(10);
+        let (slope, count) = map
+            .best_slope(bound, objective)
+            .ok_or_else(|| Error::InvalidArgumentValue("bound".into(), bound.to_string()))?;
+        println!(
+            "right {}, down {}: {} trees",
+            slope.right, slope.down, count
+        );
+        return Ok(());
+    }
+
+    let slopes = args
+        .values("slope")?
+        .into_iter()
+        .map(|slope| slope.parse())
+        .collect::<Result<Vec<Slope>, _>>()?;
+    let slopes = if slopes.is_empty() {
+        PART2_SLOPES.to_vec()
+    } else {
+        slopes
+    };
+
+    for &slope in &slopes {
+        println!(
+            "right {}, down {}: {} trees",
+            slope.right,
+            slope.down,
+            map.count_trees(slope)
+        );
+    }
+    match map.trees_product(&slopes) {
+        Some(product) => println!("Product: {}", product),
+        None => println!("Product: overflow"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use aoc_common::{Args, Error};
+use day3::{render_png, render_text, Slope, TreeMap};
+use std::io::IsTerminal;
+use std::path::Path;
+
+/// Prints the map tiled along the trajectory of `--slope RIGHT,DOWN` (3,1 by
+/// default), coloured when writing to a terminal.
+///
+/// `--png FILE` saves it as an image instead, with `--cell-size` pixels per
+/// cell (4 by default).
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let map: TreeMap = args.input()?.read_to_string()?.parse()?;
+    let slope = args
+        .value("slope")?
+        .map_or(Ok(Slope { right: 3, down: 1 }), str::parse)?;
+
+    if let Some(path) = args.value("png")? {
+        let cell_size = args.parse_value("cell-size")?.unwrap_or(4);
+        render_png(&map, slope, Path::new(path), cell_size)?;
+        println!("{} trees, saved to {}", map.count_trees(slope), path);
+    } else {
+        print!(
+            "{}",
+            render_text(&map, slope, std::io::stdout().is_terminal())
+        );
+        println!("{} trees", map.count_trees(slope));
+    }
+
+    Ok(())
+}
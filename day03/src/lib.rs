@@ -0,0 +1,123 @@
+use aoc_common::Solution;
+
+mod map;
+mod part1;
+mod part2;
+mod render;
+
+pub use map::{Error, Objective, Slope, TreeMap};
+pub use part1::part1;
+pub use part2::{part2, PART2_SLOPES};
+pub use render::{render_png, render_text};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 7);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 336);
+    }
+
+    #[test]
+    fn ignores_final_empty_lines() {
+        assert_eq!(part1("..#\n#..\n\n").unwrap(), 1);
+        assert_eq!(part1("#\r\n\r\n").unwrap(), 1);
+        assert!(part1("\n").is_err());
+    }
+
+    /// Counts trees on the unpacked grid.
+    fn count_trees_on_grid(grid: &Grid<bool>, slope: Slope) -> usize {
+        (0..grid.height())
+            .step_by(slope.down)
+            .enumerate()
+            .filter(|&(step, y)| *grid.get_wrapping(step as isize * slope.right, y as isize))
+            .count()
+    }
+
+    #[test]
+    fn packed_map_matches_grid() {
+        let wide_input: String = include_str!("../example_input")
+            .lines()
+            .map(|line| line.repeat(7) + "\n")
+            .collect();
+        for input in [include_str!("../example_input"), &wide_input] {
+            let grid: Grid<bool> = input.parse().unwrap();
+            let map: TreeMap = input.parse().unwrap();
+            assert_eq!(map.width(), grid.width());
+            for down in 1..4 {
+                for right in -80..80 {
+                    let slope = Slope { right, down };
+                    assert_eq!(map.count_trees(slope), count_trees_on_grid(&grid, slope));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_parse_slopes() {
+        assert_eq!("3,1".parse(), Ok(Slope { right: 3, down: 1 }));
+        assert_eq!("-2, 3".parse(), Ok(Slope { right: -2, down: 3 }));
+        for invalid in ["3", "3,0", "3,-1", "1,2,3", "a,1"] {
+            assert_eq!(
+                invalid.parse::<Slope>(),
+                Err(Error::InvalidSlope(invalid.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn can_find_best_slopes() {
+        let map: TreeMap = include_str!("../example_input").parse().unwrap();
+        let (slope, count) = map.best_slope(3, Objective::MostTrees).unwrap();
+        assert_eq!(count, map.count_trees(slope));
+        assert_eq!(count, 7);
+        assert_eq!(map.best_slope(3, Objective::FewestTrees).unwrap().1, 0);
+        assert_eq!(map.best_slope(0, Objective::FewestTrees), None);
+    }
+
+    #[test]
+    fn can_render_trajectory() {
+        let map: TreeMap = include_str!("../example_input").parse().unwrap();
+        let text = render_text(&map, Slope { right: 3, down: 1 }, false);
+        let rows: Vec<&str> = text.lines().collect();
+        assert_eq!(rows.len(), map.height());
+        assert!(rows.iter().all(|row| row.len() == 3 * map.width()));
+        assert_eq!(&rows[0][..11], "O.##.......");
+        assert_eq!(&rows[1][..11], "#..O#...#..");
+        assert_eq!(text.matches('X').count(), 7);
+
+        let text = render_text(&map, Slope { right: -1, down: 2 }, true);
+        let rows: Vec<&str> = text.lines().collect();
+        assert_eq!(rows[0], "..##.......\x1b[1;34mO\x1b[0m.##.......");
+        assert_eq!(rows[1], "#...#...#..".repeat(2));
+    }
+
+    #[test]
+    fn can_render_trajectory_png() {
+        let map: TreeMap = include_str!("../example_input").parse().unwrap();
+        let path = std::env::temp_dir().join("day03-trajectory.png");
+        render_png(&map, Slope { right: 3, down: 1 }, &path, 2).unwrap();
+        let png = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
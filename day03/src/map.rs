@@ -0,0 +1,146 @@
+use aoc_common::Grid;
+use std::str::FromStr;
+
+/// Toboggan map repeating infinitely to the right (and to the left).
+///
+/// Each row is packed into 64 bits words, cell `x` being bit `x % 64` of the
+/// word `x / 64` of its row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeMap {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl TreeMap {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether there is a tree at the given position, `x` wrapping around the map.
+    pub fn is_tree(&self, x: isize, y: usize) -> bool {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        self.words[y * self.words_per_row + x / 64] & (1 << (x % 64)) != 0
+    }
+
+    /// Positions visited from the top left corner until the bottom of the map.
+    pub fn trajectory(&self, slope: Slope) -> impl Iterator<Item = (isize, usize)> {
+        (0..self.height)
+            .step_by(slope.down)
+            .enumerate()
+            .map(move |(step, y)| (step as isize * slope.right, y))
+    }
+
+    pub fn count_trees(&self, slope: Slope) -> usize {
+        self.trajectory(slope)
+            .filter(|&(x, y)| self.is_tree(x, y))
+            .count()
+    }
+
+    /// Product of the tree counts of several slopes, `None` if it overflows.
+    pub fn trees_product(&self, slopes: &[Slope]) -> Option<u64> {
+        slopes.iter().try_fold(1u64, |acc, &slope| {
+            acc.checked_mul(self.count_trees(slope) as u64)
+        })
+    }
+
+    /// Slope hitting the fewest or the most trees, among the ones going at most
+    /// `bound` cells right or left and down.
+    ///
+    /// Ties are broken by the smallest `down`, then the smallest `right`.
+    pub fn best_slope(&self, bound: usize, objective: Objective) -> Option<(Slope, usize)> {
+        let bound = bound as isize;
+        (1..=bound as usize)
+            .flat_map(|down| (-bound..=bound).map(move |right| Slope { right, down }))
+            .map(|slope| (slope, self.count_trees(slope)))
+            .fold(None, |best, (slope, count)| match best {
+                Some((_, best_count)) if !objective.is_better(count, best_count) => best,
+                _ => Some((slope, count)),
+            })
+    }
+}
+
+/// Parses the rows of the map, empty lines at the end of the text being ignored.
+impl FromStr for TreeMap {
+    type Err = aoc_common::ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<bool> = s.trim_end_matches(['\n', '\r']).parse()?;
+        let words_per_row = grid.width().div_ceil(64);
+        let mut words = vec![0; words_per_row * grid.height()];
+        for ((x, y), _) in grid.iter().filter(|(_, &tree)| tree) {
+            words[y * words_per_row + x / 64] |= 1 << (x % 64);
+        }
+
+        Ok(TreeMap {
+            width: grid.width(),
+            height: grid.height(),
+            words_per_row,
+            words,
+        })
+    }
+}
+
+/// Cells moved right (left when negative) and down at each step, `down` being
+/// at least 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+/// Parses `right,down`, `3,1` or `-1,2` for instance.
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidSlope(s.to_owned());
+        let mut split_s = s.split(',');
+        let right = split_s
+            .next()
+            .and_then(|right| right.trim().parse().ok())
+            .ok_or_else(invalid)?;
+        let down = split_s
+            .next()
+            .and_then(|down| down.trim().parse().ok())
+            .filter(|&down| down > 0)
+            .ok_or_else(invalid)?;
+        if split_s.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Slope { right, down })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Objective {
+    FewestTrees,
+    MostTrees,
+}
+
+impl Objective {
+    fn is_better(self, count: usize, best_count: usize) -> bool {
+        match self {
+            Objective::FewestTrees => count < best_count,
+            Objective::MostTrees => count > best_count,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidSlope(String),
+    Render(String),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
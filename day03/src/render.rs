@@ -0,0 +1,84 @@
+use crate::{Error, Slope, TreeMap};
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+const OPEN: RGBColor = RGBColor(240, 240, 240);
+const TREE: RGBColor = RGBColor(34, 120, 50);
+const VISITED_OPEN: RGBColor = RGBColor(40, 110, 220);
+const VISITED_TREE: RGBColor = RGBColor(220, 40, 40);
+
+/// Map tiled horizontally as many times as needed to show the whole trajectory,
+/// visited cells being marked `O` when open and `X` on a tree.
+///
+/// With `colour`, visited cells are highlighted with ANSI escape codes.
+pub fn render_text(map: &TreeMap, slope: Slope, colour: bool) -> String {
+    let visited = visited_columns(map, slope);
+    let columns = tiled_columns(map, &visited);
+
+    let mut text = String::new();
+    for y in 0..map.height() {
+        for x in columns.clone() {
+            let tree = map.is_tree(x, y);
+            let cell = match (visited.get(&y) == Some(&x), tree) {
+                (true, false) if colour => "\x1b[1;34mO\x1b[0m",
+                (true, true) if colour => "\x1b[1;31mX\x1b[0m",
+                (true, false) => "O",
+                (true, true) => "X",
+                (false, false) => ".",
+                (false, true) => "#",
+            };
+            text.push_str(cell);
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Same picture as [`render_text`] saved as a PNG, each cell being a
+/// `cell_size` pixels square.
+pub fn render_png(map: &TreeMap, slope: Slope, path: &Path, cell_size: u32) -> Result<(), Error> {
+    let visited = visited_columns(map, slope);
+    let columns = tiled_columns(map, &visited);
+    let size = (
+        columns.len() as u32 * cell_size,
+        map.height() as u32 * cell_size,
+    );
+
+    let area = BitMapBackend::new(path, size).into_drawing_area();
+    for y in 0..map.height() {
+        for (column, x) in columns.clone().enumerate() {
+            let colour = match (visited.get(&y) == Some(&x), map.is_tree(x, y)) {
+                (true, false) => VISITED_OPEN,
+                (true, true) => VISITED_TREE,
+                (false, false) => OPEN,
+                (false, true) => TREE,
+            };
+            let top_left = (
+                column as i32 * cell_size as i32,
+                y as i32 * cell_size as i32,
+            );
+            let bottom_right = (top_left.0 + cell_size as i32, top_left.1 + cell_size as i32);
+            area.draw(&Rectangle::new([top_left, bottom_right], colour.filled()))
+                .map_err(|e| Error::Render(e.to_string()))?;
+        }
+    }
+
+    area.present().map_err(|e| Error::Render(e.to_string()))
+}
+
+/// Column visited on each visited row.
+fn visited_columns(map: &TreeMap, slope: Slope) -> HashMap<usize, isize> {
+    map.trajectory(slope).map(|(x, y)| (y, x)).collect()
+}
+
+/// Columns of the whole copies of the map containing the visited columns.
+fn tiled_columns(map: &TreeMap, visited: &HashMap<usize, isize>) -> Range<isize> {
+    let width = map.width() as isize;
+    let tile = |x: isize| x.div_euclid(width);
+    let first_tile = visited.values().map(|&x| tile(x)).min().unwrap_or(0);
+    let last_tile = visited.values().map(|&x| tile(x)).max().unwrap_or(0);
+    first_tile * width..(last_tile + 1) * width
+}
@@ -0,0 +1,69 @@
+use aoc_common::split_records;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day4::{parse_document, validate_batch, Schema};
+use regex::Regex;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Passport rules as they were before the schema, the regexes and the eye
+/// colour set being rebuilt for every value.
+fn valid_with_rules_rebuilt_per_value(input: &str) -> usize {
+    let number_in_range = |value: &str, min: u32, max: u32| {
+        value
+            .parse::<u32>()
+            .is_ok_and(|value| (min..=max).contains(&value))
+    };
+    split_records(input)
+        .filter(|record| {
+            let passport = parse_document(record).unwrap();
+            ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+                .iter()
+                .all(|k| {
+                    let value = match passport.get(k) {
+                        Some(value) => *value,
+                        None => return false,
+                    };
+                    match *k {
+                        "byr" => number_in_range(value, 1920, 2002),
+                        "iyr" => number_in_range(value, 2010, 2020),
+                        "eyr" => number_in_range(value, 2020, 2030),
+                        "hgt" => Regex::new(r"^([0-9]+)(cm|in)$")
+                            .unwrap()
+                            .captures(value)
+                            .is_some_and(|captures| match &captures[2] {
+                                "cm" => number_in_range(&captures[1], 150, 193),
+                                _ => number_in_range(&captures[1], 59, 76),
+                            }),
+                        "hcl" => Regex::new(r"^#([0-9a-f]{6})$").unwrap().is_match(value),
+                        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+                            .iter()
+                            .collect::<HashSet<_>>()
+                            .contains(&value),
+                        _ => value.len() == 9 && value.chars().all(char::is_numeric),
+                    }
+                })
+        })
+        .count()
+}
+
+fn validation(c: &mut Criterion) {
+    let input = vec![include_str!("../input").trim_end(); 100].join("\n\n");
+    let valid = valid_with_rules_rebuilt_per_value(&input);
+    assert_eq!(day4::part2(&input).unwrap(), valid);
+
+    let mut group = c.benchmark_group("day04_validation");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_millis(500));
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("rules_rebuilt_per_value", |b| {
+        b.iter(|| valid_with_rules_rebuilt_per_value(&input))
+    });
+    group.bench_function("schema", |b| b.iter(|| day4::part2(&input)));
+    group.bench_function("schema_streaming_parallel", |b| {
+        b.iter(|| validate_batch(input.as_bytes(), Schema::passport()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, validation);
+criterion_main!(benches);
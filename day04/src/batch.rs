@@ -0,0 +1,67 @@
+use crate::{parse_document, ParseError, Schema};
+use aoc_common::{records, Record};
+use rayon::prelude::*;
+use std::io::BufRead;
+use std::ops::Add;
+
+/// Number of documents read before validating them in parallel.
+const CHUNK_SIZE: usize = 4096;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BatchCounts {
+    pub documents: usize,
+    pub complete: usize,
+    pub valid: usize,
+}
+
+impl Add for BatchCounts {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        BatchCounts {
+            documents: self.documents + other.documents,
+            complete: self.complete + other.complete,
+            valid: self.valid + other.valid,
+        }
+    }
+}
+
+/// Counts the complete and valid documents of a batch file of any size, read
+/// by chunks of documents validated in parallel.
+///
+/// The reported parse error is the one of the first malformed document.
+pub fn validate_batch(
+    reader: impl BufRead,
+    schema: &Schema,
+) -> Result<BatchCounts, aoc_common::Error> {
+    let mut counts = BatchCounts::default();
+    let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+    for record in records(reader) {
+        chunk.push(record.map_err(aoc_common::Error::InputReadError)?);
+        if chunk.len() == CHUNK_SIZE {
+            counts = counts + validate_chunk(&chunk, schema)?;
+            chunk.clear();
+        }
+    }
+
+    Ok(counts + validate_chunk(&chunk, schema)?)
+}
+
+fn validate_chunk(
+    chunk: &[Record],
+    schema: &Schema,
+) -> Result<BatchCounts, aoc_common::ParseError<ParseError>> {
+    chunk
+        .par_iter()
+        .map(|record| {
+            let document = parse_document(record)?;
+            Ok(BatchCounts {
+                documents: 1,
+                complete: schema.is_complete(&document) as usize,
+                valid: schema.is_valid(&document) as usize,
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .try_fold(BatchCounts::default(), |acc, counts| Ok(acc + counts?))
+}
@@ -0,0 +1,61 @@
+use aoc_common::{Args, Error};
+use day4::{validate_batch, Report, Schema};
+
+/// Counts the complete and valid documents of the batch file according to the
+/// JSON schema given with `--schema`, the passport schema by default. The batch
+/// file is streamed, so it does not have to fit in memory.
+///
+/// `--report` instead lists the failures of every document, followed by the
+/// number of failures of each kind.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let loaded_schema;
+    let schema = match args.value("schema")? {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .map_err(|e| Error::InputFileOpenError(path.into(), e))?;
+            loaded_schema = Schema::from_json(&json)?;
+            &loaded_schema
+        }
+        None => Schema::passport(),
+    };
+
+    if args.flag("report") {
+        let input = args.input()?.read_to_string()?;
+        print_report(&Report::new(&input, schema));
+        return Ok(());
+    }
+
+    let counts = validate_batch(args.input()?.reader()?, schema)?;
+    println!(
+        "{} documents, {} complete, {} valid",
+        counts.documents, counts.complete, counts.valid
+    );
+
+    Ok(())
+}
+
+fn print_report(report: &Report) {
+    for (index, violations) in &report.documents {
+        match violations {
+            Ok(violations) if violations.is_empty() => println!("document {}: ok", index),
+            Ok(violations) => {
+                let violations: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                println!("document {}: {}", index, violations.join(", "))
+            }
+            Err(e) => println!(
+                "document {}: line {}, column {}: {} at `{}`",
+                index, e.line, e.column, e.kind, e.text
+            ),
+        }
+    }
+
+    println!(
+        "{} of {} documents are valid",
+        report.valid_count(),
+        report.documents.len()
+    );
+    for (failure, count) in report.summary() {
+        println!("  {}: {}", failure, count);
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::Record;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Fields of a document, by name.
+pub type Document<'a> = HashMap<&'a str, &'a str>;
+
+/// Document of a record of the batch file, made of `key:value` pairs separated
+/// by whitespace.
+pub fn parse_document(record: &Record) -> Result<Document<'_>, aoc_common::ParseError<ParseError>> {
+    record
+        .numbered_lines()
+        .try_fold(HashMap::new(), |acc, (number, line)| {
+            line.split_whitespace().try_fold(acc, |mut acc, field| {
+                let (key, value) = field.split_once(':').ok_or_else(|| {
+                    aoc_common::ParseError::new(line, field, ParseError::MissingSeparator)
+                        .at_line(number)
+                })?;
+                acc.insert(key, value);
+                Ok(acc)
+            })
+        })
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ParseError {
+    MissingSeparator,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MissingSeparator => write!(f, "field without `:`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,161 @@
+use aoc_common::{Error, Solution};
+
+mod batch;
+mod document;
+mod part1;
+mod part2;
+mod report;
+mod schema;
+
+pub use batch::{validate_batch, BatchCounts};
+pub use document::{parse_document, Document, ParseError};
+pub use part1::part1;
+pub use part2::part2;
+pub use report::{DocumentReport, Report};
+pub use schema::{Error as SchemaError, Reason, Schema, Violation};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn part1(&self, input: &str) -> Result<String, Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{split_records, Record};
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 2);
+    }
+
+    #[test]
+    fn can_solve_part2_example2() {
+        assert_eq!(part2(include_str!("../example_input2")).unwrap(), 4);
+    }
+
+    const SCHEMA: &str = r#"{
+        "fields": {
+            "byr": { "rule": { "kind": "int-range", "min": 1900, "max": 2020 } },
+            "dod": { "required": false, "rule": { "kind": "int-range", "min": 1900, "max": 2020 } },
+            "cause": { "required": false }
+        },
+        "constraints": [
+            { "kind": "not-after", "first": "byr", "second": "dod" },
+            { "kind": "requires", "field": "cause", "requires": "dod" }
+        ]
+    }"#;
+
+    #[test]
+    fn can_validate_with_custom_schema() {
+        let schema = Schema::from_json(SCHEMA).unwrap();
+        let input = "byr:1950\n\nbyr:1950 dod:2000 cause:age\n\nbyr:1950 dod:1940\n\n\
+                     byr:1950 cause:age\n\ndod:2000";
+        let records: Vec<Record> = split_records(input).collect();
+        let documents: Vec<Document> = records.iter().map(|r| parse_document(r).unwrap()).collect();
+        let valid: Vec<bool> = documents.iter().map(|d| schema.is_valid(d)).collect();
+        assert_eq!(valid, [true, true, false, false, false]);
+        assert!(!schema.is_complete(&documents[4]));
+    }
+
+    #[test]
+    fn splits_fields_on_whitespace() {
+        let record = split_records("a:1  b:2\tc:3 \n d:4").next().unwrap();
+        let document = parse_document(&record).unwrap();
+        assert_eq!(document.len(), 4);
+        assert_eq!((document["a"], document["b"]), ("1", "2"));
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        for schema in [
+            "{",
+            "{}",
+            r#"{ "fields": { "byr": { "rule": { "kind": "between" } } } }"#,
+            r#"{ "fields": { "byr": { "rule": { "kind": "int-range", "min": 1 } } } }"#,
+            r#"{ "fields": { "hcl": { "rule": { "kind": "regex", "pattern": "(" } } } }"#,
+            r#"{ "fields": {}, "constraints": [{ "kind": "requires", "field": "a" }] }"#,
+        ] {
+            assert!(Schema::from_json(schema).is_err(), "{}", schema);
+        }
+    }
+
+    #[test]
+    fn can_report_failures() {
+        let input = "ecl:gry pid:0860331470 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 hgt:190in\n\n\
+                     ecl:xry pid:860033147\neyr:2020 hcl #fffffd\n\n\
+                     ecl:gry pid:860033147 eyr:2020 hcl:#fffffd byr:1937 iyr:2017 hgt:183cm";
+        let report = Report::new(input, Schema::passport());
+        let failures: Vec<String> = report.documents[0]
+            .1
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(Violation::to_string)
+            .collect();
+        assert_eq!(
+            failures,
+            [
+                "hgt: 190in out of range 59..=76",
+                "pid: expected 9 digits, got 10"
+            ]
+        );
+        let error = report.documents[1].1.as_ref().unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (5, 10, "hcl")
+        );
+        assert_eq!(report.documents[2].1, Ok(vec![]));
+        assert_eq!(report.valid_count(), 1);
+        assert_eq!(
+            report.summary().into_iter().collect::<Vec<_>>(),
+            [
+                ("hgt: out of range".to_string(), 1),
+                ("malformed: field without `:`".to_string(), 1),
+                ("pid: wrong number of digits".to_string(), 1),
+            ]
+        );
+        assert!(part2(input).is_err());
+    }
+
+    #[test]
+    fn can_validate_batches() {
+        let input = vec![include_str!("../example_input2").trim_end(); 5000].join("\n\n");
+        let counts = validate_batch(input.as_bytes(), Schema::passport()).unwrap();
+        assert_eq!(
+            counts,
+            BatchCounts {
+                documents: 40000,
+                complete: 40000,
+                valid: 20000
+            }
+        );
+
+        let error = validate_batch(
+            "\n\nbyr:1937\n\niyr:2017 hgt\n".as_bytes(),
+            Schema::passport(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert_eq!(error, "line 5, column 10: MissingSeparator at `hgt`");
+
+        let input = (0..1000)
+            .map(|i| if i % 10 == 3 { "byr" } else { "byr:1937" })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        for _ in 0..20 {
+            let error = validate_batch(input.as_bytes(), Schema::passport()).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "line 7, column 1: MissingSeparator at `byr`"
+            );
+        }
+    }
+}
@@ -1,30 +1,9 @@
-use std::collections::HashMap;
+use crate::{parse_document, Schema};
+use aoc_common::split_records;
 
-#[derive(Debug)]
-enum Error {
-    IoError(std::io::Error),
-}
-
-fn main() -> Result<(), Error> {
-    let input = std::fs::read_to_string("input").map_err(|e| Error::IoError(e))?;
-    let count = input
-        .trim()
-        .split("\n\n")
-        .map(|s| {
-            s.split(|c| c == ' ' || c == '\n')
-                .fold(HashMap::new(), |mut acc, v| {
-                    let mut split = v.split(":");
-                    acc.insert(split.next().unwrap(), split.next().unwrap());
-                    acc
-                })
-        })
-        .filter(|passport| {
-            vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
-                .iter()
-                .all(|k| passport.contains_key(k))
-        })
-        .count();
-    println!("{:?}", count);
-
-    Ok(())
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    let schema = Schema::passport();
+    split_records(input).try_fold(0, |count, record| {
+        Ok(count + schema.is_complete(&parse_document(&record)?) as usize)
+    })
 }
@@ -0,0 +1,49 @@
+use crate::{parse_document, ParseError, Schema, Violation};
+use aoc_common::split_records;
+use std::collections::BTreeMap;
+
+/// Failures of a document, or why it could not be parsed.
+pub type DocumentReport = Result<Vec<Violation>, aoc_common::ParseError<ParseError>>;
+
+/// Validation of every document of a batch file, numbered from 1.
+#[derive(Debug)]
+pub struct Report {
+    pub documents: Vec<(usize, DocumentReport)>,
+}
+
+impl Report {
+    pub fn new(input: &str, schema: &Schema) -> Self {
+        Report {
+            documents: split_records(input)
+                .map(|record| parse_document(&record).map(|document| schema.violations(&document)))
+                .enumerate()
+                .map(|(i, violations)| (i + 1, violations))
+                .collect(),
+        }
+    }
+
+    pub fn valid_count(&self) -> usize {
+        self.documents
+            .iter()
+            .filter(|(_, violations)| violations.as_ref().is_ok_and(Vec::is_empty))
+            .count()
+    }
+
+    /// Number of failures for each field and kind of reason, malformed documents
+    /// being counted by parse error.
+    pub fn summary(&self) -> BTreeMap<String, usize> {
+        self.documents
+            .iter()
+            .flat_map(|(_, violations)| match violations {
+                Ok(violations) => violations
+                    .iter()
+                    .map(|v| format!("{}: {}", v.field, v.reason.kind()))
+                    .collect(),
+                Err(e) => vec![format!("malformed: {}", e.kind)],
+            })
+            .fold(BTreeMap::new(), |mut acc, failure| {
+                *acc.entry(failure).or_insert(0) += 1;
+                acc
+            })
+    }
+}
@@ -0,0 +1,388 @@
+use crate::Document;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+/// Fields and constraints a document has to follow, loaded from JSON:
+///
+/// ```json
+/// {
+///   "fields": {
+///     "byr": { "rule": { "kind": "int-range", "min": 1920, "max": 2002 } },
+///     "hgt": { "rule": { "kind": "unit-range", "units": { "cm": [150, 193] } } },
+///     "hcl": { "rule": { "kind": "regex", "pattern": "^#[0-9a-f]{6}$" } },
+///     "ecl": { "rule": { "kind": "one-of", "values": ["amb", "blu"] } },
+///     "pid": { "rule": { "kind": "digits", "count": 9 } },
+///     "cid": { "required": false }
+///   },
+///   "constraints": [
+///     { "kind": "not-after", "first": "byr", "second": "iyr" },
+///     { "kind": "requires", "field": "cid", "requires": "hcl" }
+///   ]
+/// }
+/// ```
+///
+/// Fields are required unless stated otherwise and fields missing from the
+/// schema are ignored.
+#[derive(Debug)]
+pub struct Schema {
+    fields: BTreeMap<String, Field>,
+    constraints: Vec<Constraint>,
+}
+
+impl Schema {
+    /// Schema of the passports of the puzzle, loaded on first use.
+    pub fn passport() -> &'static Self {
+        static PASSPORT: OnceLock<Schema> = OnceLock::new();
+        PASSPORT.get_or_init(|| {
+            Schema::from_json(include_str!("../schemas/passport.json"))
+                .expect("The passport schema is valid")
+        })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let schema: Value =
+            serde_json::from_str(json).map_err(|e| Error::InvalidJson(e.to_string()))?;
+        let fields = schema["fields"]
+            .as_object()
+            .ok_or_else(|| invalid("`fields` is not an object"))?
+            .iter()
+            .map(|(name, field)| Ok((name.clone(), Field::from_json(name, field)?)))
+            .collect::<Result<_, Error>>()?;
+        let constraints = match &schema["constraints"] {
+            Value::Null => vec![],
+            Value::Array(constraints) => constraints
+                .iter()
+                .map(Constraint::from_json)
+                .collect::<Result<_, _>>()?,
+            _ => return Err(invalid("`constraints` is not an array")),
+        };
+
+        Ok(Schema {
+            fields,
+            constraints,
+        })
+    }
+
+    /// Whether every required field is present.
+    pub fn is_complete(&self, document: &Document) -> bool {
+        self.fields
+            .iter()
+            .all(|(name, field)| !field.required || document.contains_key(name.as_str()))
+    }
+
+    /// Whether the document is complete, its fields follow their rules and the
+    /// constraints hold.
+    pub fn is_valid(&self, document: &Document) -> bool {
+        self.checks(document).all(|check| check.is_ok())
+    }
+
+    /// Every missing field, field breaking its rule and broken constraint.
+    pub fn violations(&self, document: &Document) -> Vec<Violation> {
+        self.checks(document).filter_map(Result::err).collect()
+    }
+
+    fn checks<'a>(
+        &'a self,
+        document: &'a Document<'a>,
+    ) -> impl Iterator<Item = Result<(), Violation>> + 'a {
+        let fields = self.fields.iter().map(move |(name, field)| {
+            let reason = match (document.get(name.as_str()), &field.rule) {
+                (None, _) if field.required => Err(Reason::Missing),
+                (Some(value), Some(rule)) => rule.check(value),
+                _ => Ok(()),
+            };
+            reason.map_err(|reason| Violation {
+                field: name.clone(),
+                reason,
+            })
+        });
+        let constraints = self
+            .constraints
+            .iter()
+            .map(move |constraint| constraint.check(document));
+        fields.chain(constraints)
+    }
+}
+
+/// Why a field of a document is invalid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub field: String,
+    pub reason: Reason,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    Missing,
+    NotAnInteger(String),
+    /// Value, minimum and maximum.
+    OutOfRange(String, i64, i64),
+    UnknownUnit(String),
+    NotDigits(String),
+    /// Expected and actual number of digits.
+    WrongDigitCount(usize, usize),
+    /// Value and pattern.
+    NoMatch(String, String),
+    NotAllowed(String),
+    /// Value, other field and its value.
+    After(String, String, String),
+    Requires(String),
+}
+
+impl Reason {
+    /// Short description of the reason without the values, to aggregate them.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Reason::Missing => "missing",
+            Reason::NotAnInteger(_) => "not an integer",
+            Reason::OutOfRange(..) => "out of range",
+            Reason::UnknownUnit(_) => "unknown unit",
+            Reason::NotDigits(_) => "not digits",
+            Reason::WrongDigitCount(..) => "wrong number of digits",
+            Reason::NoMatch(..) => "no match",
+            Reason::NotAllowed(_) => "not allowed",
+            Reason::After(..) => "after another field",
+            Reason::Requires(_) => "requires another field",
+        }
+    }
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reason::Missing => write!(f, "missing"),
+            Reason::NotAnInteger(value) => write!(f, "{} is not an integer", value),
+            Reason::OutOfRange(value, min, max) => {
+                write!(f, "{} out of range {}..={}", value, min, max)
+            }
+            Reason::UnknownUnit(value) => write!(f, "{} has no known unit", value),
+            Reason::NotDigits(value) => write!(f, "{} is not only digits", value),
+            Reason::WrongDigitCount(expected, actual) => {
+                write!(f, "expected {} digits, got {}", expected, actual)
+            }
+            Reason::NoMatch(value, pattern) => write!(f, "{} does not match {}", value, pattern),
+            Reason::NotAllowed(value) => write!(f, "{} is not an allowed value", value),
+            Reason::After(value, other, other_value) => {
+                write!(f, "{} is after {} {}", value, other, other_value)
+            }
+            Reason::Requires(other) => write!(f, "requires {}", other),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Field {
+    required: bool,
+    rule: Option<Rule>,
+}
+
+impl Field {
+    fn from_json(name: &str, field: &Value) -> Result<Self, Error> {
+        let required = match &field["required"] {
+            Value::Null => true,
+            required => required
+                .as_bool()
+                .ok_or_else(|| invalid(&format!("`required` of `{}` is not a boolean", name)))?,
+        };
+        let rule = match &field["rule"] {
+            Value::Null => None,
+            rule => Some(Rule::from_json(rule)?),
+        };
+
+        Ok(Field { required, rule })
+    }
+}
+
+#[derive(Debug)]
+enum Rule {
+    /// Integer between the two bounds, included.
+    IntRange(i64, i64),
+    Regex(Regex),
+    OneOf(HashSet<String>),
+    /// Exactly this many decimal digits.
+    Digits(usize),
+    /// Integer followed by a unit, the bounds depending on the unit.
+    UnitRange(BTreeMap<String, (i64, i64)>),
+}
+
+impl Rule {
+    fn from_json(rule: &Value) -> Result<Self, Error> {
+        match rule["kind"].as_str() {
+            Some("int-range") => Ok(Rule::IntRange(
+                integer(&rule["min"], "min")?,
+                integer(&rule["max"], "max")?,
+            )),
+            Some("regex") => {
+                let pattern = rule["pattern"]
+                    .as_str()
+                    .ok_or_else(|| invalid("`pattern` is not a string"))?;
+                Ok(Rule::Regex(Regex::new(pattern).map_err(Error::RegexError)?))
+            }
+            Some("one-of") => Ok(Rule::OneOf(
+                rule["values"]
+                    .as_array()
+                    .ok_or_else(|| invalid("`values` is not an array"))?
+                    .iter()
+                    .map(|value| {
+                        value
+                            .as_str()
+                            .map(str::to_owned)
+                            .ok_or_else(|| invalid("`values` are not strings"))
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            Some("digits") => Ok(Rule::Digits(
+                rule["count"]
+                    .as_u64()
+                    .ok_or_else(|| invalid("`count` is not a positive integer"))?
+                    as usize,
+            )),
+            Some("unit-range") => Ok(Rule::UnitRange(
+                rule["units"]
+                    .as_object()
+                    .ok_or_else(|| invalid("`units` is not an object"))?
+                    .iter()
+                    .map(|(unit, range)| {
+                        Ok((
+                            unit.clone(),
+                            (integer(&range[0], unit)?, integer(&range[1], unit)?),
+                        ))
+                    })
+                    .collect::<Result<_, Error>>()?,
+            )),
+            kind => Err(invalid(&format!("unknown rule kind {:?}", kind))),
+        }
+    }
+
+    fn check(&self, value: &str) -> Result<(), Reason> {
+        match self {
+            Rule::IntRange(min, max) => check_range(value, value, *min, *max),
+            Rule::Regex(regex) if regex.is_match(value) => Ok(()),
+            Rule::Regex(regex) => Err(Reason::NoMatch(value.to_owned(), regex.to_string())),
+            Rule::OneOf(values) if values.contains(value) => Ok(()),
+            Rule::OneOf(_) => Err(Reason::NotAllowed(value.to_owned())),
+            Rule::Digits(_) if !value.chars().all(|c| c.is_ascii_digit()) => {
+                Err(Reason::NotDigits(value.to_owned()))
+            }
+            Rule::Digits(count) if value.len() != *count => {
+                Err(Reason::WrongDigitCount(*count, value.len()))
+            }
+            Rule::Digits(_) => Ok(()),
+            Rule::UnitRange(units) => {
+                let unit_start = value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len());
+                let (number, unit) = value.split_at(unit_start);
+                match units.get(unit) {
+                    Some(&(min, max)) if !number.is_empty() => check_range(value, number, min, max),
+                    _ => Err(Reason::UnknownUnit(value.to_owned())),
+                }
+            }
+        }
+    }
+}
+
+/// Checks that `number`, part of `value`, is an integer between the bounds.
+fn check_range(value: &str, number: &str, min: i64, max: i64) -> Result<(), Reason> {
+    match number.parse::<i64>() {
+        Ok(number) if (min..=max).contains(&number) => Ok(()),
+        Ok(_) => Err(Reason::OutOfRange(value.to_owned(), min, max)),
+        Err(_) => Err(Reason::NotAnInteger(value.to_owned())),
+    }
+}
+
+#[derive(Debug)]
+enum Constraint {
+    /// Both fields are integers, the first one being at most the second one.
+    NotAfter(String, String),
+    /// When the first field is present, the second one has to be too.
+    Requires(String, String),
+}
+
+impl Constraint {
+    fn from_json(constraint: &Value) -> Result<Self, Error> {
+        let field = |name: &str| {
+            constraint[name]
+                .as_str()
+                .map(str::to_owned)
+                .ok_or_else(|| invalid(&format!("`{}` is not a string", name)))
+        };
+        match constraint["kind"].as_str() {
+            Some("not-after") => Ok(Constraint::NotAfter(field("first")?, field("second")?)),
+            Some("requires") => Ok(Constraint::Requires(field("field")?, field("requires")?)),
+            kind => Err(invalid(&format!("unknown constraint kind {:?}", kind))),
+        }
+    }
+
+    /// Violations are reported on the first field, missing fields being left to
+    /// the `required` checks.
+    fn check(&self, document: &Document) -> Result<(), Violation> {
+        let (field, reason) = match self {
+            Constraint::NotAfter(first, second) => {
+                match (document.get(first.as_str()), document.get(second.as_str())) {
+                    (Some(&first_value), Some(&second_value)) => {
+                        match (first_value.parse::<i64>(), second_value.parse::<i64>()) {
+                            (Ok(a), Ok(b)) if a <= b => return Ok(()),
+                            (Ok(_), Ok(_)) => (
+                                first,
+                                Reason::After(
+                                    first_value.to_owned(),
+                                    second.clone(),
+                                    second_value.to_owned(),
+                                ),
+                            ),
+                            (Err(_), _) => (first, Reason::NotAnInteger(first_value.to_owned())),
+                            (_, Err(_)) => (second, Reason::NotAnInteger(second_value.to_owned())),
+                        }
+                    }
+                    _ => return Ok(()),
+                }
+            }
+            Constraint::Requires(field, required) => {
+                if !document.contains_key(field.as_str())
+                    || document.contains_key(required.as_str())
+                {
+                    return Ok(());
+                }
+                (field, Reason::Requires(required.clone()))
+            }
+        };
+
+        Err(Violation {
+            field: field.clone(),
+            reason,
+        })
+    }
+}
+
+fn integer(value: &Value, name: &str) -> Result<i64, Error> {
+    value
+        .as_i64()
+        .ok_or_else(|| invalid(&format!("`{}` is not an integer", name)))
+}
+
+fn invalid(message: &str) -> Error {
+    Error::InvalidSchema(message.to_owned())
+}
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidJson(String),
+    InvalidSchema(String),
+    RegexError(regex::Error),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Args, Error};
+use day5::{parse_layout, BoardingPass};
+
+/// Decodes every boarding pass given with `--decode`, and encodes every seat id
+/// given with `--encode`. Without either, decodes the boarding passes of the
+/// input, one per line.
+///
+/// The layout is changed with the options of [`parse_layout`].
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let layout = parse_layout(&args)?;
+
+    let encode = args.values("encode")?;
+    let decode = args.values("decode")?;
+    if encode.is_empty() && decode.is_empty() {
+        for line in args.input()?.read_to_string()?.lines() {
+            print_pass(BoardingPass::decode(line, layout)?);
+        }
+        return Ok(());
+    }
+
+    for pass in decode {
+        print_pass(BoardingPass::decode(pass, layout)?);
+    }
+    for seat_id in encode {
+        let seat_id = seat_id
+            .parse()
+            .map_err(|_| Error::InvalidArgumentValue("encode".into(), seat_id.into()))?;
+        print_pass(BoardingPass::from_seat_id(seat_id, layout)?);
+    }
+
+    Ok(())
+}
+
+fn print_pass(pass: BoardingPass) {
+    println!(
+        "{}: row {}, column {}, seat id {}",
+        pass,
+        pass.row,
+        pass.column,
+        pass.seat_id()
+    );
+}
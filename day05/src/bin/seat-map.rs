@@ -0,0 +1,42 @@
+use aoc_common::{Args, Error};
+use day5::{parse_boarding_passes, parse_layout, SeatMap};
+
+/// Prints the seat chart of the plane built from the boarding passes of the
+/// input, followed by the duplicate passes and the missing seats.
+///
+/// `--free` also lists every free seat and `--block N` every block of at least
+/// `N` adjacent free seats in a row.
+///
+/// The layout is changed with the same options as `boarding-pass`.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let layout = parse_layout(&args)?;
+    let passes = parse_boarding_passes(&args.input()?.read_to_string()?, layout)?;
+    let seat_map = SeatMap::from_passes(layout, passes)?;
+
+    print!("{}", seat_map.chart());
+    println!("{} occupied seats", seat_map.occupied_count());
+    for pass in seat_map.duplicates() {
+        println!("duplicate pass {} for seat {}", pass, pass.seat_id());
+    }
+    for seat_id in seat_map.missing_seats() {
+        println!("missing seat {}", seat_id);
+    }
+
+    if args.flag("free") {
+        let free: Vec<String> = seat_map.free_seats().map(|id| id.to_string()).collect();
+        println!("free seats: {}", free.join(", "));
+    }
+    if let Some(length) = args.parse_value("block")? {
+        for block in seat_map.free_blocks(length) {
+            println!(
+                "row {}: columns {} to {} are free",
+                block.row,
+                block.columns.start,
+                block.columns.end - 1
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// How seats are encoded on boarding passes: the row bits come first, then the
+/// column bits, each bit being written with the letter of its value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Layout {
+    row_bits: u32,
+    column_bits: u32,
+    row_letters: [char; 2],
+    column_letters: [char; 2],
+}
+
+impl Layout {
+    /// 128 rows encoded with `F` and `B`, 8 columns encoded with `L` and `R`.
+    pub const PUZZLE: Layout = Layout {
+        row_bits: 7,
+        column_bits: 3,
+        row_letters: ['F', 'B'],
+        column_letters: ['L', 'R'],
+    };
+
+    /// `row_letters` and `column_letters` are the letters of the 0 and 1 bits,
+    /// seat ids having to fit in 32 bits.
+    pub fn new(
+        row_bits: u32,
+        column_bits: u32,
+        row_letters: [char; 2],
+        column_letters: [char; 2],
+    ) -> Result<Self, Error> {
+        if row_bits > 32
+            || column_bits > 32
+            || row_bits + column_bits > 32
+            || row_letters[0] == row_letters[1]
+            || column_letters[0] == column_letters[1]
+        {
+            return Err(Error::InvalidLayout);
+        }
+
+        Ok(Layout {
+            row_bits,
+            column_bits,
+            row_letters,
+            column_letters,
+        })
+    }
+
+    pub fn row_bits(&self) -> u32 {
+        self.row_bits
+    }
+
+    pub fn column_bits(&self) -> u32 {
+        self.column_bits
+    }
+
+    /// Total number of bits, which is also the length of a boarding pass.
+    pub fn bits(&self) -> u32 {
+        self.row_bits + self.column_bits
+    }
+
+    /// Largest seat id.
+    pub fn max_seat_id(&self) -> u32 {
+        ((1u64 << self.bits()) - 1) as u32
+    }
+}
+
+/// Seat of a boarding pass.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BoardingPass {
+    pub row: u32,
+    pub column: u32,
+    pub layout: Layout,
+}
+
+impl BoardingPass {
+    pub fn decode(pass: &str, layout: Layout) -> Result<Self, Error> {
+        let length = pass.chars().count();
+        if length != layout.bits() as usize {
+            return Err(Error::WrongLength(layout.bits() as usize, length));
+        }
+
+        let seat_id = pass.chars().enumerate().try_fold(0u32, |acc, (i, c)| {
+            let letters = if i < layout.row_bits as usize {
+                layout.row_letters
+            } else {
+                layout.column_letters
+            };
+            let bit = letters
+                .iter()
+                .position(|&letter| letter == c)
+                .ok_or(Error::InvalidCharacter(c, i))?;
+            Ok(((acc as u64) << 1 | bit as u64) as u32)
+        })?;
+
+        BoardingPass::from_seat_id(seat_id, layout)
+    }
+
+    pub fn from_seat_id(seat_id: u32, layout: Layout) -> Result<Self, Error> {
+        if seat_id > layout.max_seat_id() {
+            return Err(Error::SeatIdOutOfRange(seat_id));
+        }
+
+        Ok(BoardingPass {
+            row: ((seat_id as u64) >> layout.column_bits) as u32,
+            column: (seat_id as u64 & ((1 << layout.column_bits) - 1)) as u32,
+            layout,
+        })
+    }
+
+    pub fn seat_id(&self) -> u32 {
+        ((self.row as u64) << self.layout.column_bits | self.column as u64) as u32
+    }
+
+    pub fn encode(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Decodes a boarding pass of the puzzle layout.
+impl FromStr for BoardingPass {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BoardingPass::decode(s, Layout::PUZZLE)
+    }
+}
+
+impl Display for BoardingPass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let layout = &self.layout;
+        let row = (0..layout.row_bits)
+            .rev()
+            .map(|bit| layout.row_letters[(self.row >> bit) as usize & 1]);
+        let column = (0..layout.column_bits)
+            .rev()
+            .map(|bit| layout.column_letters[(self.column >> bit) as usize & 1]);
+        row.chain(column)
+            .try_for_each(|letter| write!(f, "{}", letter))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Character and its position, starting at 0.
+    InvalidCharacter(char, usize),
+    /// Expected and actual length.
+    WrongLength(usize, usize),
+    SeatIdOutOfRange(u32),
+    InvalidLayout,
+    /// A pass of another layout was boarded on a seat map.
+    LayoutMismatch,
+    /// Bits of a layout with more seats than a seat map can hold, see
+    /// [`SeatMap::MAX_BITS`](crate::SeatMap::MAX_BITS).
+    LayoutTooLarge(u32),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
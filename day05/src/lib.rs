@@ -0,0 +1,181 @@
+use aoc_common::{Args, Error, ParseError, Solution};
+
+mod boarding_pass;
+mod part1;
+mod part2;
+mod seat_map;
+
+pub use boarding_pass::{BoardingPass, Error as BoardingPassError, Layout};
+pub use part1::part1;
+pub use part2::part2;
+pub use seat_map::{FreeBlock, SeatMap};
+
+/// Boarding passes of the given layout, one per line.
+pub fn parse_boarding_passes(
+    input: &str,
+    layout: Layout,
+) -> Result<Vec<BoardingPass>, ParseError<BoardingPassError>> {
+    aoc_common::parse_lines(input, |line| {
+        BoardingPass::decode(line, layout).map_err(|e| {
+            let token = match e {
+                BoardingPassError::InvalidCharacter(c, i) => {
+                    let start = line.char_indices().nth(i).map_or(0, |(start, _)| start);
+                    &line[start..start + c.len_utf8()]
+                }
+                _ => line,
+            };
+            ParseError::new(line, token, e)
+        })
+    })
+}
+
+/// Layout given to the binaries, defaulting to the puzzle one and changed with
+/// `--row-bits`, `--column-bits`, `--row-letters` and `--column-letters`, the
+/// letters being those of the 0 and 1 bits: `FB` and `LR` by default.
+pub fn parse_layout(args: &Args) -> Result<Layout, Error> {
+    let letters = |name: &str, default| match args.value(name)? {
+        None => Ok(default),
+        Some(value) => match value.chars().collect::<Vec<_>>()[..] {
+            [zero, one] => Ok([zero, one]),
+            _ => Err(Error::InvalidArgumentValue(name.into(), value.into())),
+        },
+    };
+
+    Ok(Layout::new(
+        args.parse_value("row-bits")?
+            .unwrap_or(Layout::PUZZLE.row_bits()),
+        args.parse_value("column-bits")?
+            .unwrap_or(Layout::PUZZLE.column_bits()),
+        letters("row-letters", ['F', 'B'])?,
+        letters("column-letters", ['L', 'R'])?,
+    )?)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn part1(&self, input: &str) -> Result<String, Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 820);
+    }
+
+    #[test]
+    fn can_decode_and_encode_boarding_passes() {
+        let pass: BoardingPass = "FBFBBFFRLR".parse().unwrap();
+        assert_eq!((pass.row, pass.column, pass.seat_id()), (44, 5, 357));
+        assert_eq!(pass.encode(), "FBFBBFFRLR");
+        for seat_id in 0..=Layout::PUZZLE.max_seat_id() {
+            let pass = BoardingPass::from_seat_id(seat_id, Layout::PUZZLE).unwrap();
+            assert_eq!(
+                pass.encode().parse::<BoardingPass>().unwrap().seat_id(),
+                seat_id
+            );
+        }
+
+        let layout = Layout::new(2, 1, ['a', 'b'], ['0', '1']).unwrap();
+        let pass = BoardingPass::decode("ba1", layout).unwrap();
+        assert_eq!((pass.row, pass.column, pass.seat_id()), (2, 1, 5));
+        assert_eq!(
+            BoardingPass::from_seat_id(6, layout).unwrap().encode(),
+            "bb0"
+        );
+        let full = Layout::new(20, 12, ['F', 'B'], ['L', 'R']).unwrap();
+        assert_eq!(full.max_seat_id(), u32::MAX);
+        let pass = BoardingPass::from_seat_id(u32::MAX, full).unwrap();
+        assert_eq!(BoardingPass::decode(&pass.encode(), full), Ok(pass));
+    }
+
+    #[test]
+    fn rejects_invalid_boarding_passes() {
+        use BoardingPassError::*;
+        assert_eq!("FBFBBFFRL".parse::<BoardingPass>(), Err(WrongLength(10, 9)));
+        assert_eq!(
+            "FBFBBFFRLRR".parse::<BoardingPass>(),
+            Err(WrongLength(10, 11))
+        );
+        assert_eq!(
+            "FBFBBFFRLX".parse::<BoardingPass>(),
+            Err(InvalidCharacter('X', 9))
+        );
+        assert_eq!(
+            "FBFBBFRRLR".parse::<BoardingPass>(),
+            Err(InvalidCharacter('R', 6))
+        );
+        assert_eq!(
+            BoardingPass::from_seat_id(1024, Layout::PUZZLE),
+            Err(SeatIdOutOfRange(1024))
+        );
+        assert_eq!(
+            Layout::new(30, 3, ['F', 'B'], ['L', 'R']),
+            Err(InvalidLayout)
+        );
+        assert_eq!(
+            Layout::new(7, 3, ['F', 'F'], ['L', 'R']),
+            Err(InvalidLayout)
+        );
+
+        let error = parse_boarding_passes("FBFBBFFRLR\nFBFBBFFXLR", Layout::PUZZLE).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn can_analyse_seat_maps() {
+        let layout = Layout::new(2, 3, ['F', 'B'], ['L', 'R']).unwrap();
+        let passes = [0, 1, 2, 4, 5, 6, 12, 13, 14, 5, 30, 31, 12]
+            .iter()
+            .map(|&seat_id| BoardingPass::from_seat_id(seat_id, layout).unwrap());
+        let map = SeatMap::from_passes(layout, passes).unwrap();
+
+        assert_eq!(map.occupied_count(), 11);
+        assert_eq!(map.highest_occupied(), Some(31));
+        assert_eq!(map.missing_seats().collect::<Vec<_>>(), [3]);
+        assert_eq!(map.free_seats().take(4).collect::<Vec<_>>(), [3, 7, 8, 9]);
+        let duplicates: Vec<u32> = map.duplicates().iter().map(|p| p.seat_id()).collect();
+        assert_eq!(duplicates, [5, 12]);
+        assert_eq!(
+            map.free_blocks(3).collect::<Vec<_>>(),
+            [
+                FreeBlock {
+                    row: 1,
+                    columns: 0..4
+                },
+                FreeBlock {
+                    row: 2,
+                    columns: 0..8
+                },
+                FreeBlock {
+                    row: 3,
+                    columns: 0..6
+                },
+            ]
+        );
+        assert_eq!(
+            map.chart(),
+            "0 ###.#!#.\n1 ....!##.\n2 ........\n3 ......##\n"
+        );
+
+        let pass = BoardingPass::from_seat_id(0, Layout::PUZZLE).unwrap();
+        assert_eq!(
+            SeatMap::new(layout).unwrap().board(pass),
+            Err(BoardingPassError::LayoutMismatch)
+        );
+        let layout = Layout::new(16, 16, ['F', 'B'], ['L', 'R']).unwrap();
+        assert_eq!(
+            SeatMap::new(layout).unwrap_err(),
+            BoardingPassError::LayoutTooLarge(32)
+        );
+    }
+}
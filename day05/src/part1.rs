@@ -1,29 +1,11 @@
-#[derive(Debug)]
-enum Error {
-    NoInput,
-}
-
-fn main() -> Result<(), Error> {
-    let entries = std::fs::read_to_string("input").map_err(|_| Error::NoInput)?;
-    let max_seat_id = entries.split("\n").fold(0, |acc, value| {
-        let id = compute_id(value);
-        if acc > id {
-            acc
-        } else {
-            id
-        }
-    });
-
-    println!("max_seat_id: {}", max_seat_id);
-    Ok(())
-}
-
-fn compute_id(string: &str) -> i32 {
-    string.chars().rev().enumerate().fold(0, |mut acc, (i, v)| {
-        if v == 'B' || v == 'R' {
-            acc += 1 << i;
-        }
-
-        acc
-    })
+use crate::{parse_boarding_passes, Layout, SeatMap};
+
+pub fn part1(entries: &str) -> Result<u32, aoc_common::Error> {
+    let seat_map = SeatMap::from_passes(
+        Layout::PUZZLE,
+        parse_boarding_passes(entries, Layout::PUZZLE)?,
+    )?;
+    seat_map
+        .highest_occupied()
+        .ok_or_else(|| aoc_common::Error::Solver("No boarding pass found".into()))
 }
@@ -1,29 +1,10 @@
-use std::collections::HashSet;
-
-#[derive(Debug)]
-enum Error {
-    NoInput,
-}
-
-fn main() -> Result<(), Error> {
-    const ENTRY_SIZE: u32 = 10;
-    let entries = std::fs::read_to_string("input").map_err(|_| Error::NoInput)?;
-    let ids: HashSet<i32> = entries.split("\n").map(compute_id).collect();
-    for i in 0..(1 << ENTRY_SIZE) {
-        if !ids.contains(&i) && ids.contains(&(i + 1)) && ids.contains(&(i - 1)) {
-            println!("{}", i);
-            break;
-        }
-    }
-    Ok(())
-}
-
-fn compute_id(string: &str) -> i32 {
-    string.chars().rev().enumerate().fold(0, |mut acc, (i, v)| {
-        if v == 'B' || v == 'R' {
-            acc += 1 << i;
-        }
-
-        acc
-    })
+use crate::{parse_boarding_passes, Layout, SeatMap};
+
+pub fn part2(entries: &str) -> Result<u32, aoc_common::Error> {
+    let seat_map = SeatMap::from_passes(
+        Layout::PUZZLE,
+        parse_boarding_passes(entries, Layout::PUZZLE)?,
+    )?;
+    let missing_seat = seat_map.missing_seats().next();
+    missing_seat.ok_or_else(|| aoc_common::Error::Solver("No free seat found".into()))
 }
@@ -0,0 +1,136 @@
+use crate::{BoardingPass, BoardingPassError, Layout};
+use aoc_common::BitSet;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Occupancy of every seat of the plane, one bit per seat id.
+#[derive(Debug, Clone)]
+pub struct SeatMap {
+    layout: Layout,
+    occupied: BitSet,
+    duplicates: Vec<BoardingPass>,
+}
+
+/// Adjacent free seats of a row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FreeBlock {
+    pub row: u32,
+    pub columns: Range<u32>,
+}
+
+impl SeatMap {
+    /// Largest number of bits of the layout of a seat map, the map allocating
+    /// one bit per seat id: 2 MiB for 24 bits.
+    pub const MAX_BITS: u32 = 24;
+
+    pub fn new(layout: Layout) -> Result<Self, BoardingPassError> {
+        if layout.bits() > SeatMap::MAX_BITS {
+            return Err(BoardingPassError::LayoutTooLarge(layout.bits()));
+        }
+
+        Ok(SeatMap {
+            layout,
+            occupied: BitSet::new(layout.max_seat_id() as usize + 1),
+            duplicates: vec![],
+        })
+    }
+
+    pub fn from_passes(
+        layout: Layout,
+        passes: impl IntoIterator<Item = BoardingPass>,
+    ) -> Result<Self, BoardingPassError> {
+        passes
+            .into_iter()
+            .try_fold(SeatMap::new(layout)?, |mut map, pass| {
+                map.board(pass)?;
+                Ok(map)
+            })
+    }
+
+    /// Marks the seat of `pass` as occupied, a pass for an already occupied seat
+    /// being recorded as a duplicate.
+    pub fn board(&mut self, pass: BoardingPass) -> Result<(), BoardingPassError> {
+        if pass.layout != self.layout {
+            return Err(BoardingPassError::LayoutMismatch);
+        }
+        if !self.occupied.insert(pass.seat_id() as usize) {
+            self.duplicates.push(pass);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_occupied(&self, seat_id: u32) -> bool {
+        self.occupied.contains(seat_id as usize)
+    }
+
+    pub fn occupied_count(&self) -> usize {
+        self.occupied.count()
+    }
+
+    pub fn highest_occupied(&self) -> Option<u32> {
+        self.occupied.ones().last().map(|seat_id| seat_id as u32)
+    }
+
+    pub fn free_seats(&self) -> impl Iterator<Item = u32> + '_ {
+        self.occupied.zeros().map(|seat_id| seat_id as u32)
+    }
+
+    /// Free seats whose previous and next seat ids are occupied.
+    pub fn missing_seats(&self) -> impl Iterator<Item = u32> + '_ {
+        self.free_seats().filter(move |&seat_id| {
+            seat_id > 0
+                && self.is_occupied(seat_id - 1)
+                && seat_id
+                    .checked_add(1)
+                    .is_some_and(|next| self.is_occupied(next))
+        })
+    }
+
+    /// Passes boarding an already occupied seat, in boarding order.
+    pub fn duplicates(&self) -> &[BoardingPass] {
+        &self.duplicates
+    }
+
+    /// Free blocks of at least `length` adjacent seats, row by row.
+    pub fn free_blocks(&self, length: u32) -> impl Iterator<Item = FreeBlock> + '_ {
+        let columns = 1usize << self.layout.column_bits();
+        (0..1u64 << self.layout.row_bits()).flat_map(move |row| {
+            let first_seat = row as usize * columns;
+            self.occupied
+                .zero_runs(first_seat..first_seat + columns)
+                .filter(move |run| run.len() >= length as usize)
+                .map(move |run| FreeBlock {
+                    row: row as u32,
+                    columns: (run.start - first_seat) as u32..(run.end - first_seat) as u32,
+                })
+        })
+    }
+
+    /// One line per row: `#` for an occupied seat, `!` for a seat boarded more
+    /// than once and `.` for a free seat.
+    pub fn chart(&self) -> String {
+        let rows = 1u64 << self.layout.row_bits();
+        let columns = 1u64 << self.layout.column_bits();
+        let label_width = (rows - 1).to_string().len();
+        let duplicates: HashSet<u32> = self.duplicates.iter().map(|pass| pass.seat_id()).collect();
+
+        let mut chart = String::new();
+        for row in 0..rows {
+            chart.push_str(&format!("{:>width$} ", row, width = label_width));
+            for column in 0..columns {
+                let seat_id = (row * columns + column) as u32;
+                chart.push(if duplicates.contains(&seat_id) {
+                    '!'
+                } else if self.is_occupied(seat_id) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            chart.push('\n');
+        }
+
+        chart
+    }
+}
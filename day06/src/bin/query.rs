@@ -0,0 +1,24 @@
+use aoc_common::{records, Args, Error};
+use day6::{parse_group, Query};
+
+/// Evaluates `--query` for every group of the input, `any` by default, and
+/// prints the questions of each group followed by the total count. The input is
+/// read one group at a time.
+///
+/// See [`Query`] for the syntax, `"#1 - #2"` giving for instance the questions
+/// answered by the first person of a group but not by the second one.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let query: Query = args.value("query")?.unwrap_or("any").parse()?;
+
+    let mut total = 0;
+    for (i, record) in records(args.input()?.reader()?).enumerate() {
+        let group = parse_group(&record.map_err(Error::InputReadError)?)?;
+        let answers = query.evaluate(&group);
+        println!("group {}: {} ({})", i + 1, answers, answers.count());
+        total += answers.count();
+    }
+    println!("total: {}", total);
+
+    Ok(())
+}
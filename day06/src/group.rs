@@ -0,0 +1,136 @@
+use crate::Error;
+use aoc_common::{split_records, ParseError, Record};
+use std::fmt::{Display, Formatter};
+
+/// Questions from `a` to `z`, question `a` being the lowest bit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Answers(pub u32);
+
+impl Answers {
+    pub const NONE: Answers = Answers(0);
+    pub const ALL: Answers = Answers((1 << 26) - 1);
+
+    pub fn question(question: char) -> Option<Self> {
+        match question {
+            'a'..='z' => Some(Answers(1 << (question as u8 - b'a'))),
+            _ => None,
+        }
+    }
+
+    pub fn contains(self, question: u8) -> bool {
+        self.0 & (1 << question) != 0
+    }
+
+    pub fn count(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Answers(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Answers(self.0 & !other.0)
+    }
+
+    pub fn complement(self) -> Self {
+        Answers::ALL.difference(self)
+    }
+}
+
+/// Letters of the questions, in alphabetical order.
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        (0..26)
+            .filter(|&question| self.contains(question))
+            .try_for_each(|question| write!(f, "{}", (b'a' + question) as char))
+    }
+}
+
+/// Answers of each person of a group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub people: Vec<Answers>,
+}
+
+impl Group {
+    /// Questions answered by anyone in the group.
+    pub fn anyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::NONE, |acc, &answers| acc.union(answers))
+    }
+
+    /// Questions answered by everyone in the group.
+    pub fn everyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::ALL, |acc, &answers| acc.intersection(answers))
+    }
+
+    /// Questions answered by exactly `count` people.
+    pub fn exactly(&self, count: usize) -> Answers {
+        self.answered_by(|people| people == count)
+    }
+
+    /// Questions answered by at least `count` people.
+    pub fn at_least(&self, count: usize) -> Answers {
+        self.answered_by(|people| people >= count)
+    }
+
+    /// Questions answered by the person at this position, starting at 1, no
+    /// question when the group is smaller.
+    pub fn person(&self, position: usize) -> Answers {
+        position
+            .checked_sub(1)
+            .and_then(|i| self.people.get(i))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn answered_by(&self, filter: impl Fn(usize) -> bool) -> Answers {
+        (0..26)
+            .filter(|&question| {
+                filter(
+                    self.people
+                        .iter()
+                        .filter(|answers| answers.contains(question))
+                        .count(),
+                )
+            })
+            .fold(Answers::NONE, |acc, question| {
+                acc.union(Answers(1 << question))
+            })
+    }
+}
+
+/// Groups separated by blank lines, each line being the questions answered by a
+/// person.
+pub fn parse_groups(input: &str) -> Result<Vec<Group>, ParseError<Error>> {
+    split_records(input)
+        .map(|record| parse_group(&record))
+        .collect()
+}
+
+pub fn parse_group(record: &Record) -> Result<Group, ParseError<Error>> {
+    let people = record
+        .numbered_lines()
+        .map(|(number, line)| parse_person(line).map_err(|e| e.at_line(number)))
+        .collect::<Result<_, _>>()?;
+    Ok(Group { people })
+}
+
+fn parse_person(line: &str) -> Result<Answers, ParseError<Error>> {
+    line.char_indices()
+        .try_fold(Answers::NONE, |acc, (i, question)| {
+            let answer = Answers::question(question).ok_or_else(|| {
+                let text = &line[i..i + question.len_utf8()];
+                ParseError::new(line, text, Error::InvalidAnswer(question))
+            })?;
+            Ok(acc.union(answer))
+        })
+}
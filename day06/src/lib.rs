@@ -0,0 +1,111 @@
+use aoc_common::Solution;
+
+mod group;
+mod part1;
+mod part2;
+mod query;
+
+pub use group::{parse_group, parse_groups, Answers, Group};
+pub use part1::part1;
+pub use part2::part2;
+pub use query::Query;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidAnswer(char),
+    InvalidPerson(String),
+    UnknownToken(String),
+    UnexpectedToken,
+    UnexpectedEndOfQuery,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 11);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 6);
+    }
+
+    #[test]
+    fn can_solve_windows_edited_example() {
+        let input = include_str!("../example_input").replace('\n', " \r\n") + "\r\n  \r\n";
+        assert_eq!(part1(&input).unwrap(), 11);
+        assert_eq!(part2(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn can_evaluate_queries() {
+        let groups = parse_groups(include_str!("../example_input")).unwrap();
+        let evaluate = |query: &str| -> Vec<String> {
+            let query: Query = query.parse().unwrap();
+            groups
+                .iter()
+                .map(|group| query.evaluate(group).to_string())
+                .collect()
+        };
+        assert_eq!(evaluate("any"), ["abc", "abc", "abc", "a", "b"]);
+        assert_eq!(evaluate("all"), ["abc", "", "a", "a", "b"]);
+        assert_eq!(evaluate("exactly 1"), ["abc", "abc", "bc", "", "b"]);
+        assert_eq!(evaluate("#1 - #2"), ["abc", "a", "b", "", "b"]);
+        assert_eq!(
+            evaluate("!any & [abz] | all ^ [b]"),
+            ["acz", "bz", "abz", "az", "az"]
+        );
+        assert_eq!(evaluate("(atleast 2 | #3) & ![c]"), ["", "", "a", "a", ""]);
+        assert_eq!("any".parse::<Query>().unwrap().total(&groups), 11);
+        assert_eq!("all".parse::<Query>().unwrap().total(&groups), 6);
+    }
+
+    #[test]
+    fn rejects_invalid_queries() {
+        let error = |query: &str| {
+            let error = query.parse::<Query>().unwrap_err();
+            (error.column, error.text, error.kind)
+        };
+        assert_eq!(error("any &"), (6, "".into(), Error::UnexpectedEndOfQuery));
+        assert_eq!(error("any all"), (5, "all".into(), Error::UnexpectedToken));
+        assert_eq!(
+            error("exactly all"),
+            (9, "all".into(), Error::UnexpectedToken)
+        );
+        assert_eq!(error("(any"), (5, "".into(), Error::UnexpectedEndOfQuery));
+        assert_eq!(
+            error("none"),
+            (1, "none".into(), Error::UnknownToken("none".into()))
+        );
+        assert_eq!(
+            error("#0"),
+            (1, "#0".into(), Error::InvalidPerson("#0".into()))
+        );
+        assert_eq!(error("[aB]"), (1, "[aB]".into(), Error::InvalidAnswer('B')));
+        assert_eq!(
+            error("[ab"),
+            (1, "[ab".into(), Error::UnknownToken("[ab".into()))
+        );
+
+        let error = parse_groups("ab\n\nc1").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (3, 2, Error::InvalidAnswer('1'))
+        );
+    }
+}
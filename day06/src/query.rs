@@ -0,0 +1,256 @@
+use crate::{Answers, Error, Group};
+use aoc_common::ParseError;
+use std::iter::Peekable;
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+/// Set of questions computed for each group.
+///
+/// Queries are written with these terms:
+///
+/// - `any`: questions answered by anyone,
+/// - `all`: questions answered by everyone,
+/// - `exactly N`: questions answered by exactly `N` people,
+/// - `atleast N`: questions answered by at least `N` people,
+/// - `#N`: questions answered by the `N`th person of the group, from 1,
+/// - `[abc]`: the questions `a`, `b` and `c`,
+///
+/// combined with `!` (complement), then `&` (intersection), then `|` (union),
+/// `-` (difference) and `^` (symmetric difference), and with parentheses.
+/// `#1 - #2` is for instance the questions answered by the first person of a
+/// group but not by the second one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    Anyone,
+    Everyone,
+    Exactly(usize),
+    AtLeast(usize),
+    Person(usize),
+    Questions(Answers),
+    Complement(Box<Query>),
+    Intersection(Box<Query>, Box<Query>),
+    Union(Box<Query>, Box<Query>),
+    Difference(Box<Query>, Box<Query>),
+    SymmetricDifference(Box<Query>, Box<Query>),
+}
+
+impl Query {
+    pub fn evaluate(&self, group: &Group) -> Answers {
+        match self {
+            Query::Anyone => group.anyone(),
+            Query::Everyone => group.everyone(),
+            Query::Exactly(count) => group.exactly(*count),
+            Query::AtLeast(count) => group.at_least(*count),
+            Query::Person(position) => group.person(*position),
+            Query::Questions(answers) => *answers,
+            Query::Complement(query) => query.evaluate(group).complement(),
+            Query::Intersection(lhs, rhs) => lhs.evaluate(group).intersection(rhs.evaluate(group)),
+            Query::Union(lhs, rhs) => lhs.evaluate(group).union(rhs.evaluate(group)),
+            Query::Difference(lhs, rhs) => lhs.evaluate(group).difference(rhs.evaluate(group)),
+            Query::SymmetricDifference(lhs, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate(group), rhs.evaluate(group));
+                lhs.union(rhs).difference(lhs.intersection(rhs))
+            }
+        }
+    }
+
+    /// Sum over the groups of the number of questions of the query.
+    pub fn total(&self, groups: &[Group]) -> usize {
+        groups
+            .iter()
+            .map(|group| self.evaluate(group).count())
+            .sum()
+    }
+}
+
+impl FromStr for Query {
+    type Err = ParseError<Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_query(s).map_err(|e| e.at_line(1))
+    }
+}
+
+fn parse_query(s: &str) -> Result<Query, ParseError<Error>> {
+    let mut parser = Parser {
+        line: s,
+        tokens: tokenize(s)?.into_iter().peekable(),
+    };
+    let query = parser.expression()?;
+    match parser.tokens.next() {
+        None => Ok(query),
+        token => Err(parser.unexpected(token)),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Token {
+    Anyone,
+    Everyone,
+    Exactly,
+    AtLeast,
+    Number(usize),
+    Person(usize),
+    Questions(Answers),
+    Complement,
+    Intersection,
+    Union,
+    Difference,
+    SymmetricDifference,
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+/// Recursive descent parser over the tokens of a query, each token being kept
+/// with its text to locate errors.
+struct Parser<'a> {
+    line: &'a str,
+    tokens: Peekable<IntoIter<(&'a str, Token)>>,
+}
+
+impl<'a> Parser<'a> {
+    fn expression(&mut self) -> Result<Query, ParseError<Error>> {
+        let mut query = self.term()?;
+        while let Some(&(_, token)) = self.tokens.peek() {
+            let combine = match token {
+                Token::Union => Query::Union,
+                Token::Difference => Query::Difference,
+                Token::SymmetricDifference => Query::SymmetricDifference,
+                _ => break,
+            };
+            self.tokens.next();
+            query = combine(Box::new(query), Box::new(self.term()?));
+        }
+
+        Ok(query)
+    }
+
+    fn term(&mut self) -> Result<Query, ParseError<Error>> {
+        let mut query = self.factor()?;
+        while let Some((_, Token::Intersection)) = self.tokens.peek() {
+            self.tokens.next();
+            query = Query::Intersection(Box::new(query), Box::new(self.factor()?));
+        }
+
+        Ok(query)
+    }
+
+    fn factor(&mut self) -> Result<Query, ParseError<Error>> {
+        match self.tokens.next() {
+            Some((_, Token::Anyone)) => Ok(Query::Anyone),
+            Some((_, Token::Everyone)) => Ok(Query::Everyone),
+            Some((_, Token::Exactly)) => Ok(Query::Exactly(self.number()?)),
+            Some((_, Token::AtLeast)) => Ok(Query::AtLeast(self.number()?)),
+            Some((_, Token::Person(position))) => Ok(Query::Person(position)),
+            Some((_, Token::Questions(answers))) => Ok(Query::Questions(answers)),
+            Some((_, Token::Complement)) => Ok(Query::Complement(Box::new(self.factor()?))),
+            Some((_, Token::LeftParenthesis)) => {
+                let query = self.expression()?;
+                match self.tokens.next() {
+                    Some((_, Token::RightParenthesis)) => Ok(query),
+                    token => Err(self.unexpected(token)),
+                }
+            }
+            token => Err(self.unexpected(token)),
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, ParseError<Error>> {
+        match self.tokens.next() {
+            Some((_, Token::Number(number))) => Ok(number),
+            token => Err(self.unexpected(token)),
+        }
+    }
+
+    fn unexpected(&self, token: Option<(&'a str, Token)>) -> ParseError<Error> {
+        match token {
+            Some((text, _)) => ParseError::new(self.line, text, Error::UnexpectedToken),
+            None => ParseError::new(
+                self.line,
+                &self.line[self.line.len()..],
+                Error::UnexpectedEndOfQuery,
+            ),
+        }
+    }
+}
+
+fn tokenize(line: &str) -> Result<Vec<(&str, Token)>, ParseError<Error>> {
+    let mut tokens = vec![];
+    let mut characters = line.char_indices().peekable();
+    while let Some((start, character)) = characters.next() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        let mut end = start + character.len_utf8();
+        let continues: fn(char) -> bool = match character {
+            c if c.is_ascii_alphabetic() => |c| c.is_ascii_alphabetic(),
+            c if c.is_ascii_digit() || c == '#' => |c| c.is_ascii_digit(),
+            '[' => |c| c != ']',
+            _ => |_| false,
+        };
+        while let Some(&(i, c)) = characters.peek() {
+            if !continues(c) {
+                break;
+            }
+            end = i + c.len_utf8();
+            characters.next();
+        }
+        if character == '[' {
+            if let Some((i, c)) = characters.next() {
+                end = i + c.len_utf8();
+            }
+        }
+
+        let text = &line[start..end];
+        tokens.push((
+            text,
+            text.parse().map_err(|e| ParseError::new(line, text, e))?,
+        ));
+    }
+
+    Ok(tokens)
+}
+
+impl FromStr for Token {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse() {
+            return Ok(Token::Number(number));
+        }
+        if let Some(position) = s.strip_prefix('#') {
+            return match position.parse() {
+                Ok(position) if position > 0 => Ok(Token::Person(position)),
+                _ => Err(Error::InvalidPerson(s.into())),
+            };
+        }
+        if let Some(questions) = s.strip_prefix('[') {
+            return questions
+                .strip_suffix(']')
+                .ok_or_else(|| Error::UnknownToken(s.into()))?
+                .chars()
+                .try_fold(Answers::NONE, |acc, question| {
+                    Answers::question(question)
+                        .map(|answer| acc.union(answer))
+                        .ok_or(Error::InvalidAnswer(question))
+                })
+                .map(Token::Questions);
+        }
+
+        Ok(match s {
+            "any" => Token::Anyone,
+            "all" => Token::Everyone,
+            "exactly" => Token::Exactly,
+            "atleast" => Token::AtLeast,
+            "!" => Token::Complement,
+            "&" => Token::Intersection,
+            "|" => Token::Union,
+            "-" => Token::Difference,
+            "^" => Token::SymmetricDifference,
+            "(" => Token::LeftParenthesis,
+            ")" => Token::RightParenthesis,
+            _ => return Err(Error::UnknownToken(s.into())),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::{Args, Error};
+use day7::BagGraph;
+
+/// Prints, for each `--color` (`shiny gold` by default), the number of colours
+/// eventually containing it and the number of bags it contains. `--list` also
+/// prints the containing colours.
+fn main() -> Result<(), Error> {
+    let args = Args::from_env()?;
+    let graph: BagGraph = args.input()?.read_to_string()?.parse()?;
+
+    let mut colors = args.values("color")?;
+    if colors.is_empty() {
+        colors.push("shiny gold");
+    }
+
+    for color in colors {
+        let containers = graph.containers_of(color)?;
+        println!(
+            "{}: contained by {} colours, contains {} bags",
+            color,
+            containers.len(),
+            graph.total_contents(color)?
+        );
+        if args.flag("list") {
+            for container in containers {
+                println!("  {}", container);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use crate::{Error, Rule};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::rc::Rc;
+
+/// Index of a colour in the graph.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct ColorId(usize);
+
+/// Bags each colour has to contain, checked to reference only colours having
+/// their own rule and to never contain themselves.
+///
+/// Queries are memoised, and walk the graph with a stack on the heap so that
+/// deep rule sets do not overflow the call stack.
+#[derive(Debug)]
+pub struct BagGraph {
+    colors: Vec<String>,
+    ids: HashMap<String, ColorId>,
+    /// Quantity and colour of the bags directly contained by each colour.
+    contents: Vec<Vec<(u32, ColorId)>>,
+    /// Colours directly containing each colour.
+    containers: Vec<Vec<ColorId>>,
+    containers_cache: RefCell<Vec<Option<Rc<BTreeSet<ColorId>>>>>,
+    total_contents_cache: RefCell<Vec<Option<u64>>>,
+}
+
+impl BagGraph {
+    pub fn new(rules: Vec<Rule>) -> Result<Self, Error> {
+        let mut ids = HashMap::new();
+        for (i, rule) in rules.iter().enumerate() {
+            if ids.insert(rule.color.clone(), ColorId(i)).is_some() {
+                return Err(Error::DuplicateRule(rule.color.clone()));
+            }
+        }
+
+        let mut contents = vec![vec![]; rules.len()];
+        let mut containers = vec![vec![]; rules.len()];
+        for (i, rule) in rules.iter().enumerate() {
+            for (quantity, color) in &rule.valid_content {
+                let id = *ids
+                    .get(color)
+                    .ok_or_else(|| Error::UnknownColor(color.clone()))?;
+                contents[i].push((*quantity, id));
+                containers[id.0].push(ColorId(i));
+            }
+        }
+
+        let graph = BagGraph {
+            colors: rules.into_iter().map(|rule| rule.color).collect(),
+            ids,
+            containers_cache: RefCell::new(vec![None; contents.len()]),
+            total_contents_cache: RefCell::new(vec![None; contents.len()]),
+            contents,
+            containers,
+        };
+        if let Some(cycle) = graph.find_cycle() {
+            return Err(Error::Cycle(
+                cycle.iter().map(|&id| graph.color(id).to_owned()).collect(),
+            ));
+        }
+
+        Ok(graph)
+    }
+
+    /// Colours having a rule, in the order of the rules.
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.colors.iter().map(String::as_str)
+    }
+
+    /// Colours of the bags eventually containing a bag of `color`, sorted.
+    pub fn containers_of(&self, color: &str) -> Result<Vec<&str>, Error> {
+        let containers = memoise(
+            &mut self.containers_cache.borrow_mut(),
+            self.id(color)?,
+            |id| self.containers[id.0].clone(),
+            |id, cache| {
+                Ok(Rc::new(self.containers[id.0].iter().fold(
+                    BTreeSet::new(),
+                    |mut acc, &container| {
+                        acc.insert(container);
+                        acc.extend(cache[container.0].as_deref().into_iter().flatten());
+                        acc
+                    },
+                )))
+            },
+        )?;
+
+        let mut colors: Vec<&str> = containers.iter().map(|&id| self.color(id)).collect();
+        colors.sort_unstable();
+        Ok(colors)
+    }
+
+    /// Number of bags a bag of `color` contains.
+    pub fn total_contents(&self, color: &str) -> Result<u64, Error> {
+        memoise(
+            &mut self.total_contents_cache.borrow_mut(),
+            self.id(color)?,
+            |id| {
+                self.contents[id.0]
+                    .iter()
+                    .map(|&(_, inner)| inner)
+                    .collect()
+            },
+            |id, cache| {
+                self.contents[id.0]
+                    .iter()
+                    .try_fold(0u64, |acc, &(quantity, inner)| {
+                        cache[inner.0]?
+                            .checked_add(1)?
+                            .checked_mul(quantity as u64)?
+                            .checked_add(acc)
+                    })
+                    .ok_or(Error::Overflow)
+            },
+        )
+    }
+
+    fn id(&self, color: &str) -> Result<ColorId, Error> {
+        self.ids
+            .get(color)
+            .copied()
+            .ok_or_else(|| Error::UnknownColor(color.to_owned()))
+    }
+
+    fn color(&self, id: ColorId) -> &str {
+        &self.colors[id.0]
+    }
+
+    /// Colours of a cycle of the contents, each one containing the next one and
+    /// the last one containing the first one.
+    fn find_cycle(&self) -> Option<Vec<ColorId>> {
+        #[derive(Copy, Clone, PartialEq)]
+        enum Mark {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        let mut marks = vec![Mark::Unvisited; self.colors.len()];
+        for root in 0..self.colors.len() {
+            if marks[root] != Mark::Unvisited {
+                continue;
+            }
+
+            marks[root] = Mark::InProgress;
+            let mut stack = vec![(ColorId(root), 0)];
+            while let Some(&(id, next_content)) = stack.last() {
+                let inner = match self.contents[id.0].get(next_content) {
+                    Some(&(_, inner)) => inner,
+                    None => {
+                        marks[id.0] = Mark::Done;
+                        stack.pop();
+                        continue;
+                    }
+                };
+
+                if let Some(last) = stack.last_mut() {
+                    last.1 += 1;
+                }
+                match marks[inner.0] {
+                    Mark::Unvisited => {
+                        marks[inner.0] = Mark::InProgress;
+                        stack.push((inner, 0));
+                    }
+                    Mark::InProgress => {
+                        let start = stack.iter().position(|&(id, _)| id == inner)?;
+                        return Some(stack[start..].iter().map(|&(id, _)| id).collect());
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Value of `start`, computed by `value` once the values of its `dependencies`
+/// are in `cache`, which keeps every computed value.
+///
+/// The dependencies must not contain cycles.
+fn memoise<T: Clone>(
+    cache: &mut [Option<T>],
+    start: ColorId,
+    dependencies: impl Fn(ColorId) -> Vec<ColorId>,
+    value: impl Fn(ColorId, &[Option<T>]) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let mut stack = vec![start];
+    while let Some(&id) = stack.last() {
+        if cache[id.0].is_some() {
+            stack.pop();
+            continue;
+        }
+
+        let missing: Vec<ColorId> = dependencies(id)
+            .into_iter()
+            .filter(|dependency| cache[dependency.0].is_none())
+            .collect();
+        if missing.is_empty() {
+            cache[id.0] = Some(value(id, cache)?);
+            stack.pop();
+        } else {
+            stack.extend(missing);
+        }
+    }
+
+    Ok(cache[start.0].clone().expect("The start value is computed"))
+}
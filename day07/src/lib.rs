@@ -0,0 +1,236 @@
+mod graph;
+
+pub use graph::BagGraph;
+
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::iter::Peekable;
+use std::num::ParseIntError;
+use std::str::{FromStr, Split};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    let graph: BagGraph = input.parse()?;
+    Ok(graph.containers_of("shiny gold")?.len())
+}
+
+pub fn part2(input: &str) -> Result<u64, aoc_common::Error> {
+    let graph: BagGraph = input.parse()?;
+    Ok(graph.total_contents("shiny gold")?)
+}
+
+pub fn parse_rules(input: &str) -> Result<Vec<Rule>, ParseError<Error>> {
+    parse_lines(input, parse_rule)
+}
+
+impl FromStr for BagGraph {
+    type Err = aoc_common::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(BagGraph::new(parse_rules(s)?)?)
+    }
+}
+
+fn parse_rule(rule_string: &str) -> Result<Rule, ParseError<Error>> {
+    let mut words = Words {
+        line: rule_string,
+        words: rule_string.split(' ').peekable(),
+    };
+    let color = parse_color(&mut words)?;
+    eat_any(&["bags"], &mut words)?;
+    eat_any(&["contain"], &mut words)?;
+
+    let mut valid_content = vec![];
+    loop {
+        let quantity = parse_quantity(&mut words)?;
+        if quantity == 0 && words.peek() == Some("other") {
+            break;
+        }
+
+        let color = parse_color(&mut words)?;
+        valid_content.push((quantity, color));
+        match words.peek() {
+            Some("bag.") | Some("bags.") => break,
+            _ => eat_any(&["bag,", "bags,"], &mut words)?,
+        }
+    }
+
+    Ok(Rule {
+        color,
+        valid_content,
+    })
+}
+
+/// Words of a rule, the errors being located in its line.
+struct Words<'a> {
+    line: &'a str,
+    words: Peekable<Split<'a, char>>,
+}
+
+impl<'a> Words<'a> {
+    fn peek(&mut self) -> Option<&'a str> {
+        self.words.peek().copied()
+    }
+
+    /// Next word, `missing` being reported at the end of the line if there is none.
+    fn expect(&mut self, missing: Error) -> Result<&'a str, ParseError<Error>> {
+        let line = self.line;
+        self.words
+            .next()
+            .ok_or_else(|| ParseError::new(line, &line[line.len()..], missing))
+    }
+
+    fn error(&self, word: &str, kind: Error) -> ParseError<Error> {
+        ParseError::new(self.line, word, kind)
+    }
+}
+
+fn parse_color(words: &mut Words) -> Result<String, ParseError<Error>> {
+    let adjective = words.expect(Error::ColorAdjectiveNotFound)?;
+    let color = words.expect(Error::ColorNotFound)?;
+
+    Ok(adjective.to_string() + " " + color)
+}
+
+fn parse_quantity(words: &mut Words) -> Result<u32, ParseError<Error>> {
+    let quantity = words.expect(Error::QuantityNotFound)?;
+    if quantity == "no" {
+        Ok(0)
+    } else {
+        u32::from_str(quantity).map_err(|e| words.error(quantity, Error::QuantityParseError(e)))
+    }
+}
+
+fn eat_any(expected_words: &[&str], words: &mut Words) -> Result<(), ParseError<Error>> {
+    let word = words.expect(Error::UnexpectedEndOfString)?;
+    if expected_words.contains(&word) {
+        Ok(())
+    } else {
+        Err(words.error(word, Error::UnexpectedWord(word.into())))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub color: String,
+    pub valid_content: Vec<(u32, String)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    ColorAdjectiveNotFound,
+    ColorNotFound,
+    QuantityNotFound,
+    QuantityParseError(ParseIntError),
+    UnexpectedEndOfString,
+    UnexpectedWord(String),
+    /// Colour without a rule, either contained by a rule or queried.
+    UnknownColor(String),
+    DuplicateRule(String),
+    /// Colours each containing the next one, the last one containing the first
+    /// one.
+    Cycle(Vec<String>),
+    Overflow,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 4);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 32);
+    }
+
+    #[test]
+    fn can_solve_part2_example2() {
+        assert_eq!(part2(include_str!("../example_input2")).unwrap(), 126);
+    }
+
+    #[test]
+    fn can_query_any_color() {
+        let graph: BagGraph = include_str!("../example_input").parse().unwrap();
+        assert_eq!(
+            graph.containers_of("faded blue").unwrap(),
+            vec![
+                "bright white",
+                "dark olive",
+                "dark orange",
+                "light red",
+                "muted yellow",
+                "shiny gold",
+                "vibrant plum",
+            ]
+        );
+        assert!(graph.containers_of("light red").unwrap().is_empty());
+        assert_eq!(graph.total_contents("light red").unwrap(), 186);
+        assert_eq!(graph.total_contents("dark olive").unwrap(), 7);
+        assert_eq!(
+            graph.total_contents("plaid red"),
+            Err(Error::UnknownColor("plaid red".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_cycles_and_dangling_colors() {
+        let cycle = "light red bags contain 1 bright white bag.
+bright white bags contain 2 muted yellow bags, 1 faded blue bag.
+muted yellow bags contain 1 light red bag.
+faded blue bags contain no other bags.";
+        assert_eq!(
+            BagGraph::new(parse_rules(cycle).unwrap()).unwrap_err(),
+            Error::Cycle(vec![
+                "light red".into(),
+                "bright white".into(),
+                "muted yellow".into()
+            ])
+        );
+
+        let dangling = "light red bags contain 1 bright white bag.";
+        assert_eq!(
+            BagGraph::new(parse_rules(dangling).unwrap()).unwrap_err(),
+            Error::UnknownColor("bright white".into())
+        );
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = parse_rules("faded blue bags contain no other bags.\nlight red bags contain 1")
+            .unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 25, Error::ColorAdjectiveNotFound)
+        );
+        let error = parse_rules("light red bags contain x bright white bags.").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 24, "x")
+        );
+        let error = parse_rules("light red bags hold 1 bright white bag.").unwrap_err();
+        assert_eq!(
+            (error.column, error.kind),
+            (16, Error::UnexpectedWord("hold".into()))
+        );
+    }
+}
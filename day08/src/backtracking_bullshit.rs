@@ -1,28 +1,15 @@
+use crate::{parse_instructions, swap_instruction, Instruction, InstructionType};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
-use std::str::FromStr;
-
-type Instruction = (InstructionType, i32);
 
 /// This solution is my first attempt to solve the challenge
 /// I went for a backtracking solution but when I realized I had to find a way to flip nop
 /// it was late and I was tired so I just gave up and wrote
 /// the bruteforce solution.
-fn main() -> Result<(), Error> {
-    let file = File::open("input").map_err(|_| Error::FileNotFound)?;
-    let reader = BufReader::new(file);
-    let instructions: Vec<Instruction> = reader.lines().try_fold(Vec::new(), |mut acc, line| {
-        let line = line.map_err(|_| Error::FileReadError)?;
-        let mut split_line = line.split(" ");
-        acc.push((
-            split_line.next().ok_or(Error::InstructionNotFound)?.into(),
-            i32::from_str(split_line.next().ok_or(Error::ArgumentNotFound)?)
-                .map_err(|e| Error::ParseArgumentError(e))?,
-        ));
-        Ok(acc)
-    })?;
+///
+/// It only finds the answer of some programs: on the example it runs out of
+/// instructions to swap and returns an error.
+pub fn part2_backtracking(input: &str) -> Result<i32, aoc_common::Error> {
+    let instructions = parse_instructions(input)?;
 
     let part1 = false;
     let mut pc = 0;
@@ -30,21 +17,18 @@ fn main() -> Result<(), Error> {
     let mut visited_instructions = HashSet::new();
     let mut swapped = HashSet::new();
     let mut state_history = vec![];
-    let mut step = 0;
     loop {
         if pc as usize >= instructions.len() || (part1 && visited_instructions.contains(&pc)) {
             break;
         }
 
-        step += 1;
-
         let mut instruction = instructions[pc as usize];
         if !part1 && visited_instructions.contains(&pc) {
             let swappable_state = restore_to_swappable_instruction_state(
                 &mut state_history,
                 &instructions,
                 &mut swapped,
-            );
+            )?;
             pc = swappable_state.0;
             acc = swappable_state.1;
             instruction = swap_instruction(pc, &instructions);
@@ -62,66 +46,30 @@ fn main() -> Result<(), Error> {
         state_history.push((pc, acc));
     }
 
-    println!("{}", acc);
-
-    Ok(())
+    Ok(acc)
 }
 
 fn is_instruction_swappable(instruction_type: InstructionType) -> bool {
     instruction_type == InstructionType::Jmp || instruction_type == InstructionType::Nop
 }
 
-fn swap_instruction(pc: i32, instructions: &Vec<Instruction>) -> Instruction {
-    let instruction = instructions[pc as usize];
-    match instruction {
-        (InstructionType::Nop, value) => (InstructionType::Jmp, value),
-        (InstructionType::Jmp, value) => (InstructionType::Nop, value),
-        _ => instruction,
-    }
-}
-
 fn restore_to_swappable_instruction_state(
     state_history: &mut Vec<(i32, i32)>,
-    instructions: &Vec<Instruction>,
+    instructions: &[Instruction],
     swapped: &mut HashSet<i32>,
-) -> (i32, i32) {
-    let mut state = state_history.pop().unwrap();
+) -> Result<(i32, i32), aoc_common::Error> {
+    let no_swappable_instruction =
+        || aoc_common::Error::Solver("No swappable instruction left to backtrack to".into());
+    let mut state = state_history.pop().ok_or_else(no_swappable_instruction)?;
     let mut instruction = instructions[state.0 as usize];
     while !is_instruction_swappable(instruction.0) || swapped.contains(&state.0) {
-        state = state_history.pop().unwrap();
+        state = state_history.pop().ok_or_else(no_swappable_instruction)?;
         instruction = instructions[state.0 as usize];
     }
 
     swapped.insert(state.0);
-    (
-        state_history.last().unwrap().0,
-        state_history.last().unwrap().1,
-    )
-}
-
-#[derive(Debug, Copy, Clone, PartialEq)]
-enum InstructionType {
-    Nop,
-    Jmp,
-    Acc,
-}
-
-impl Into<InstructionType> for &str {
-    fn into(self) -> InstructionType {
-        match self {
-            "nop" => InstructionType::Nop,
-            "jmp" => InstructionType::Jmp,
-            "acc" => InstructionType::Acc,
-            _ => panic!("Unknown instruction"),
-        }
-    }
-}
-
-#[derive(Debug)]
-enum Error {
-    FileNotFound,
-    FileReadError,
-    InstructionNotFound,
-    ArgumentNotFound,
-    ParseArgumentError(ParseIntError),
+    state_history
+        .last()
+        .copied()
+        .ok_or_else(no_swappable_instruction)
 }
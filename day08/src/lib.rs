@@ -0,0 +1,182 @@
+use aoc_common::{parse_lines, trace, ParseError, Solution};
+use std::collections::HashSet;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+mod backtracking_bullshit;
+
+pub use backtracking_bullshit::part2_backtracking;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+type Instruction = (InstructionType, i32);
+
+pub fn part1(input: &str) -> Result<i32, aoc_common::Error> {
+    let instructions = parse_instructions(input)?;
+    Ok(run(&instructions, None).1)
+}
+
+pub fn part2(input: &str) -> Result<i32, aoc_common::Error> {
+    let instructions = parse_instructions(input)?;
+    let flippable_instructions =
+        instructions
+            .iter()
+            .enumerate()
+            .fold(vec![], |mut acc, (i, &(instruction, _))| {
+                if instruction == InstructionType::Jmp || instruction == InstructionType::Nop {
+                    acc.push(i);
+                }
+
+                acc
+            });
+
+    for flippable_instruction in flippable_instructions {
+        let result = run(&instructions, Some(flippable_instruction));
+        trace!("is {} swappable ?", flippable_instruction);
+        if result.0 as usize == instructions.len() {
+            trace!("yes: {}", result.1);
+            return Ok(result.1);
+        } else {
+            trace!("no\n");
+        }
+    }
+
+    Err(aoc_common::Error::Solver(
+        "No instruction fixes the program".into(),
+    ))
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError<Error>> {
+    parse_lines(input, |line| {
+        let mut split_line = line.split(' ');
+        let instruction = split_line
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| ParseError::new(line, line, Error::InstructionNotFound))?;
+        let argument = split_line
+            .next()
+            .ok_or_else(|| ParseError::new(line, &line[line.len()..], Error::ArgumentNotFound))?;
+        Ok((
+            instruction
+                .parse()
+                .map_err(|e| ParseError::new(line, instruction, e))?,
+            i32::from_str(argument)
+                .map_err(|e| ParseError::new(line, argument, Error::ParseArgumentError(e)))?,
+        ))
+    })
+}
+
+fn run(instructions: &[Instruction], flippable_instruction: Option<usize>) -> (i32, i32) {
+    let mut pc = 0;
+    let mut acc = 0;
+    let mut visited_instructions = HashSet::new();
+    loop {
+        if pc as usize >= instructions.len() || visited_instructions.contains(&pc) {
+            break;
+        }
+
+        let instruction = if Some(pc as usize) == flippable_instruction {
+            swap_instruction(pc, instructions)
+        } else {
+            instructions[pc as usize]
+        };
+        visited_instructions.insert(pc);
+        match instruction {
+            (InstructionType::Nop, _) => pc += 1,
+            (InstructionType::Acc, value) => {
+                acc += value;
+                pc += 1
+            }
+            (InstructionType::Jmp, value) => pc += value,
+        }
+    }
+
+    (pc, acc)
+}
+
+fn swap_instruction(pc: i32, instructions: &[Instruction]) -> Instruction {
+    let instruction = instructions[pc as usize];
+    match instruction {
+        (InstructionType::Nop, value) => (InstructionType::Jmp, value),
+        (InstructionType::Jmp, value) => (InstructionType::Nop, value),
+        _ => instruction,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum InstructionType {
+    Nop,
+    Jmp,
+    Acc,
+}
+
+impl FromStr for InstructionType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "nop" => InstructionType::Nop,
+            "jmp" => InstructionType::Jmp,
+            "acc" => InstructionType::Acc,
+            _ => return Err(Error::UnknownInstruction),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    InstructionNotFound,
+    UnknownInstruction,
+    ArgumentNotFound,
+    ParseArgumentError(ParseIntError),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_input2() {
+        assert_eq!(part1(include_str!("../input2")).unwrap(), 5);
+    }
+
+    #[test]
+    fn can_solve_part2_input2() {
+        assert_eq!(part2(include_str!("../input2")).unwrap(), 8);
+    }
+
+    #[test]
+    fn backtracking_reports_unsolved_example() {
+        assert!(part2_backtracking(include_str!("../input2")).is_err());
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = parse_instructions("nop +0\nacc +1\njpm -2").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (3, 1, "jpm")
+        );
+        let error = parse_instructions("nop +0\nacc 1x").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 5, "1x")
+        );
+    }
+}
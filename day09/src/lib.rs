@@ -0,0 +1,109 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+const PREAMBLE_LENGTH: usize = 25;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, aoc_common::Error> {
+    let numbers = parse_numbers(input)?;
+    Ok(not_the_sum_of_last_numbers(&numbers, PREAMBLE_LENGTH))
+}
+
+pub fn part2(input: &str) -> Result<u64, aoc_common::Error> {
+    let numbers = parse_numbers(input)?;
+    let not_the_sum_number = not_the_sum_of_last_numbers(&numbers, PREAMBLE_LENGTH);
+    Ok(compute_encryption_weakness(not_the_sum_number, &numbers))
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u64>, ParseError<ParseIntError>> {
+    parse_lines(input, |line| {
+        u64::from_str(line).map_err(|e| ParseError::new(line, line, e))
+    })
+}
+
+fn compute_encryption_weakness(target_number: u64, numbers: &[u64]) -> u64 {
+    let mut result = 0;
+    let mut max_range_value = 0;
+    let mut min_range_value = 0;
+    for i in 0..numbers.len() {
+        let mut sum = 0;
+        let mut range_length = 0usize;
+        while sum < target_number {
+            sum += numbers[i + range_length];
+
+            if range_length == 0 {
+                min_range_value = numbers[i + range_length];
+                max_range_value = numbers[i + range_length];
+            } else if numbers[i + range_length] > max_range_value {
+                max_range_value = numbers[i + range_length];
+            } else if numbers[i + range_length] < min_range_value {
+                min_range_value = numbers[i + range_length];
+            }
+
+            range_length += 1;
+        }
+
+        if sum == target_number && range_length > 1 {
+            result = min_range_value + max_range_value;
+            break;
+        }
+    }
+
+    result
+}
+
+fn not_the_sum_of_last_numbers(numbers: &[u64], preamble_length: usize) -> u64 {
+    let mut result = 0;
+    for i in preamble_length..numbers.len() {
+        let number = numbers[i];
+        let mut low_index = 0;
+        let mut high_index = preamble_length - 1;
+        let mut preamble = numbers[i - preamble_length..=i - 1].to_vec();
+        preamble.sort();
+        let mut sum = preamble[low_index] + preamble[high_index];
+        while number != sum {
+            if low_index == high_index {
+                result = number;
+                break;
+            }
+
+            sum = preamble[low_index] + preamble[high_index];
+
+            if sum < number {
+                low_index += 1;
+            } else if sum > number {
+                high_index -= 1;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_input2() {
+        let numbers = parse_numbers(include_str!("../input2")).unwrap();
+        let not_the_sum_number = not_the_sum_of_last_numbers(&numbers, 5);
+        assert_eq!(not_the_sum_number, 127);
+        assert_eq!(
+            compute_encryption_weakness(not_the_sum_number, &numbers),
+            62
+        );
+    }
+}
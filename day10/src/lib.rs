@@ -0,0 +1,111 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::num::ParseIntError;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32, aoc_common::Error> {
+    let adapters = parse_adapters(input)?;
+    Ok(diff1_times_diff3(&adapters))
+}
+
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    let adapters = parse_adapters(input)?;
+    let mut cache = HashMap::new();
+    let adapters_set: HashSet<&i32> = adapters.iter().collect();
+    let max_adapter = adapters.iter().max().copied().unwrap_or(0);
+    Ok(compute_arrangements_count(
+        0,
+        max_adapter,
+        &mut cache,
+        &adapters_set,
+    ))
+}
+
+fn parse_adapters(input: &str) -> Result<Vec<i32>, ParseError<ParseIntError>> {
+    parse_lines(input, |line| {
+        line.parse().map_err(|e| ParseError::new(line, line, e))
+    })
+}
+
+fn compute_arrangements_count(
+    value: i32,
+    max_adapter: i32,
+    cache: &mut HashMap<i32, usize>,
+    adapters: &HashSet<&i32>,
+) -> usize {
+    if value == max_adapter {
+        return 1;
+    }
+
+    let mut sum = 0;
+    for v in value + 1..=value + 3 {
+        if adapters.contains(&v) {
+            if cache.contains_key(&v) {
+                sum += cache.get(&v).unwrap();
+            } else {
+                sum += compute_arrangements_count(v, max_adapter, cache, adapters);
+            }
+        }
+    }
+
+    cache.entry(value).or_insert(sum);
+    sum
+}
+
+fn diff1_times_diff3(adapters: &[i32]) -> i32 {
+    let mut adapters = adapters.to_vec();
+    adapters.sort_by(|a, b| b.cmp(a));
+    let mut differences: HashMap<i32, i32> = HashMap::new();
+    let mut rating = 0;
+    while let Some(adapter_rating) = adapters.pop() {
+        let diff = adapter_rating - rating;
+        rating += diff;
+        *differences.entry(diff).or_default() += 1;
+    }
+    *differences.entry(3).or_default() += 1;
+
+    let difference_1 = *differences.get(&1).unwrap_or(&0);
+    let difference_3 = *differences.get(&3).unwrap_or(&0);
+    difference_1 * difference_3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_input2() {
+        assert_eq!(part1(include_str!("../input2")).unwrap(), 35);
+    }
+
+    #[test]
+    fn can_solve_part1_input3() {
+        assert_eq!(part1(include_str!("../input3")).unwrap(), 220);
+    }
+
+    #[test]
+    fn can_solve_part1_without_1_jolt_differences() {
+        assert_eq!(part1("3\n6").unwrap(), 0);
+    }
+
+    #[test]
+    fn can_solve_part2_input2() {
+        assert_eq!(part2(include_str!("../input2")).unwrap(), 8);
+    }
+
+    #[test]
+    fn can_solve_part2_input3() {
+        assert_eq!(part2(include_str!("../input3")).unwrap(), 19208);
+    }
+}
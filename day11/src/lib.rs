@@ -0,0 +1,114 @@
+use aoc_common::{Grid, Solution, NEIGHBOURS_8};
+
+const EMPTY_SEAT: u8 = b'L';
+const OCCUPIED_SEAT: u8 = b'#';
+
+type SeatMap = Grid<u8>;
+type Rule = fn(usize, usize, &SeatMap) -> bool;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    let seat_map: SeatMap = input.parse()?;
+    Ok(stable_occupied_seat_count(
+        &seat_map,
+        should_become_empty_p1,
+        should_become_occupied_p1,
+    ))
+}
+
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    let seat_map: SeatMap = input.parse()?;
+    Ok(stable_occupied_seat_count(
+        &seat_map,
+        should_become_empty_p2,
+        should_become_occupied_p2,
+    ))
+}
+
+fn stable_occupied_seat_count(seat_map: &SeatMap, empty_rule: Rule, occupied_rule: Rule) -> usize {
+    let mut previous_seat_map = seat_map.clone();
+    let mut seat_map = compute_next_state(seat_map, empty_rule, occupied_rule);
+    while previous_seat_map != seat_map {
+        previous_seat_map = seat_map.clone();
+        seat_map = compute_next_state(&seat_map, empty_rule, occupied_rule);
+    }
+
+    seat_map.iter().filter(|(_, &v)| v == OCCUPIED_SEAT).count()
+}
+
+fn compute_next_state(seat_map: &SeatMap, empty_rule: Rule, occupied_rule: Rule) -> SeatMap {
+    let mut next_state: SeatMap = seat_map.clone();
+    for (x, y) in seat_map.positions() {
+        let seat = next_state.get_mut(x as isize, y as isize).unwrap();
+        if empty_rule(x, y, seat_map) {
+            *seat = EMPTY_SEAT
+        } else if occupied_rule(x, y, seat_map) {
+            *seat = OCCUPIED_SEAT
+        }
+    }
+
+    next_state
+}
+
+fn should_become_empty_p1(x: usize, y: usize, seat_map: &SeatMap) -> bool {
+    seat_map.row(y)[x] == OCCUPIED_SEAT && count_adjacent(x, y, seat_map, OCCUPIED_SEAT) >= 4
+}
+
+fn should_become_occupied_p1(x: usize, y: usize, seat_map: &SeatMap) -> bool {
+    seat_map.row(y)[x] == EMPTY_SEAT && count_adjacent(x, y, seat_map, OCCUPIED_SEAT) == 0
+}
+
+fn should_become_empty_p2(x: usize, y: usize, seat_map: &SeatMap) -> bool {
+    seat_map.row(y)[x] == OCCUPIED_SEAT && count_visible(x, y, seat_map, OCCUPIED_SEAT) >= 5
+}
+
+fn should_become_occupied_p2(x: usize, y: usize, seat_map: &SeatMap) -> bool {
+    seat_map.row(y)[x] == EMPTY_SEAT && count_visible(x, y, seat_map, OCCUPIED_SEAT) == 0
+}
+
+fn count_adjacent(x: usize, y: usize, seat_map: &SeatMap, state: u8) -> usize {
+    seat_map
+        .neighbours_8(x, y)
+        .filter(|&(x, y)| seat_map.row(y)[x] == state)
+        .count()
+}
+
+/// Counts the first seats seen in each direction that are in the given state.
+fn count_visible(x: usize, y: usize, seat_map: &SeatMap, state: u8) -> usize {
+    NEIGHBOURS_8
+        .iter()
+        .filter(|&&direction| {
+            seat_map
+                .ray(x, y, direction)
+                .map(|(_, &seat)| seat)
+                .find(|&seat| seat != b'.')
+                == Some(state)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_input2() {
+        assert_eq!(part1(include_str!("../input2")).unwrap(), 37);
+    }
+
+    #[test]
+    fn can_solve_part2_input2() {
+        assert_eq!(part2(include_str!("../input2")).unwrap(), 26);
+    }
+}
@@ -0,0 +1,196 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::convert::TryFrom;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part2(input: &str) -> Result<i32, aoc_common::Error> {
+    let actions = parse_lines(input, |line| {
+        line.parse::<Action>().map_err(|e| {
+            let token = match e {
+                Error::ParseValueError(_) | Error::InvalidAngle(_) => line.get(1..).unwrap_or(""),
+                Error::ParseActionError(_) | Error::UnknownDirection(_) => line,
+            };
+            ParseError::new(line, token, e)
+        })
+    })?;
+
+    let mut ship = Ship::new();
+    for action in actions {
+        ship.perform_action(action);
+    }
+
+    Ok(ship.manhattan_distance_from_origin())
+}
+
+#[derive(Debug)]
+struct Ship {
+    position: Position,
+    waypoint_position: Position,
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Ship {
+            position: Position(0, 0),
+            waypoint_position: Position(10, 1),
+        }
+    }
+
+    pub fn perform_action(&mut self, action: Action) {
+        match action {
+            Action::MoveForward(value) => {
+                self.navigate(value);
+            }
+            Action::Move(d, value) => {
+                self.move_waypoint(d, value);
+            }
+            Action::TurnLeft(degrees) => {
+                self.rotate_waypoint(-degrees);
+            }
+            Action::TurnRight(degrees) => {
+                self.rotate_waypoint(degrees);
+            }
+        }
+    }
+
+    fn move_waypoint(&mut self, d: Direction, value: i32) {
+        match d {
+            Direction::North => self.waypoint_position.1 += value,
+            Direction::East => self.waypoint_position.0 += value,
+            Direction::South => self.waypoint_position.1 -= value,
+            Direction::West => self.waypoint_position.0 -= value,
+        }
+    }
+
+    fn navigate(&mut self, steps: i32) {
+        self.position.0 += self.waypoint_position.0 * steps;
+        self.position.1 += self.waypoint_position.1 * steps;
+    }
+
+    fn rotate_waypoint(&mut self, relative_angle_degrees: i32) {
+        const SIN: [i32; 4] = [0, 1, 0, -1];
+        const COS: [i32; 4] = [1, 0, -1, 0];
+
+        let i = (relative_angle_degrees / 90).rem_euclid(4) as usize;
+
+        self.waypoint_position = Position(
+            self.waypoint_position.0 * COS[i] + self.waypoint_position.1 * SIN[i],
+            -self.waypoint_position.0 * SIN[i] + self.waypoint_position.1 * COS[i],
+        );
+    }
+
+    fn manhattan_distance_from_origin(&mut self) -> i32 {
+        self.position.0.abs() + self.position.1.abs()
+    }
+}
+
+#[derive(Debug)]
+enum Action {
+    Move(Direction, i32),
+    TurnLeft(i32),
+    TurnRight(i32),
+    MoveForward(i32),
+}
+
+impl FromStr for Action {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let action = s.as_bytes().first().copied();
+        let value = i32::from_str(s.get(1..).ok_or(Error::ParseActionError(action))?)
+            .map_err(Error::ParseValueError)?;
+        if matches!(action, Some(b'L') | Some(b'R')) && value % 90 != 0 {
+            return Err(Error::InvalidAngle(value));
+        }
+
+        Ok(match action {
+            Some(b'L') => Action::TurnLeft(value),
+            Some(b'R') => Action::TurnRight(value),
+            Some(b'F') => Action::MoveForward(value),
+            Some(direction) => Action::Move(Direction::try_from(direction)?, value),
+            None => return Err(Error::ParseActionError(action)),
+        })
+    }
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'N' => Direction::North,
+            b'S' => Direction::South,
+            b'E' => Direction::East,
+            b'W' => Direction::West,
+            _ => return Err(Error::UnknownDirection(value as char)),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Position(i32, i32);
+
+#[derive(Debug)]
+pub enum Error {
+    ParseValueError(ParseIntError),
+    ParseActionError(Option<u8>),
+    UnknownDirection(char),
+    /// A turn angle that is not a multiple of 90 degrees.
+    InvalidAngle(i32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part2_input2() {
+        assert_eq!(part2(include_str!("../input2")).unwrap(), 286);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part2("F10\nX3\nR90").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: UnknownDirection('X') at `X3`"
+        );
+        let error = part2("F10\nN3\n\nR90").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: ParseActionError(None) at ``"
+        );
+        let error = part2("F1O").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 2: ParseValueError"));
+        let error = part2("F10\nR45").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: InvalidAngle(45) at `45`"
+        );
+    }
+
+    #[test]
+    fn can_turn_more_than_a_full_turn() {
+        assert_eq!(part2("R450\nF1").unwrap(), part2("R90\nF1").unwrap());
+        assert_eq!(part2("L450\nF1").unwrap(), part2("L90\nF1").unwrap());
+        assert_eq!(part2("L90\nF1").unwrap(), part2("R270\nF1").unwrap());
+    }
+}
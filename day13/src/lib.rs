@@ -0,0 +1,137 @@
+use aoc_common::{ParseError, Solution};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, aoc_common::Error> {
+    let mut lines = input.lines();
+
+    let timestamp_line = lines.next().ok_or(Error::TimestampNotFound)?;
+    let timestamp = u32::from_str(timestamp_line).map_err(|e| {
+        ParseError::new(timestamp_line, timestamp_line, Error::ParseIntError(e)).at_line(1)
+    })?;
+
+    let bus_ids = parse_bus_ids(lines.next().ok_or(Error::BusIdsNotFound)?)?;
+
+    let bus_id: u32 = bus_ids
+        .into_iter()
+        .flatten()
+        .min_by(|&schedule_a, &schedule_b| {
+            bus_from(timestamp, schedule_a).cmp(&bus_from(timestamp, schedule_b))
+        })
+        .ok_or(Error::NoResultFound)?;
+
+    Ok(bus_id * (bus_from(timestamp, bus_id) - timestamp))
+}
+
+pub fn part2(input: &str) -> Result<i64, aoc_common::Error> {
+    let bus_ids = parse_bus_ids(input.lines().nth(1).ok_or(Error::BusIdsNotFound)?)?;
+
+    let eqs: Vec<(i64, i64)> = bus_ids
+        .iter()
+        .enumerate()
+        .filter_map(|(i, bus_id)| bus_id.map(|bus_id| (i, bus_id as i64)))
+        .map(|(i, bus_id)| ((-(i as i64) % bus_id + bus_id) % bus_id, bus_id))
+        .collect();
+
+    let t: i64 = eqs.iter().map(|e| e.1).product();
+    Ok(eqs.iter().fold(0i64, |acc, &(n, ni)| {
+        let nci = t / ni;
+        acc + n * nci * modular_inverse(ni, nci)
+    }) % t)
+}
+
+/// Bus ids of the second line of the notes, `x` being an out of service bus.
+fn parse_bus_ids(line: &str) -> Result<Vec<Option<u32>>, ParseError<Error>> {
+    line.split(',')
+        .map(|id| match id {
+            "x" => Ok(None),
+            id => match u32::from_str(id) {
+                Ok(0) => Err(ParseError::new(line, id, Error::InvalidBusId)),
+                Ok(bus_id) => Ok(Some(bus_id)),
+                Err(e) => Err(ParseError::new(line, id, Error::ParseIntError(e))),
+            },
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(2))
+}
+
+fn modular_inverse(a: i64, b: i64) -> i64 {
+    let (_, _, v) = extended_euclide(a, b);
+    (v % a + a) % a
+}
+
+fn extended_euclide(a: i64, b: i64) -> (i64, i64, i64) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (dp, up, vp) = extended_euclide(b, a % b);
+            (dp, vp, up - (a / b) * vp)
+        }
+    }
+}
+
+fn bus_from(timestamp: u32, bus_id: u32) -> u32 {
+    let mut bus_timestamp = 0;
+    while timestamp > bus_timestamp {
+        bus_timestamp += bus_id;
+    }
+
+    bus_timestamp
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ParseIntError(ParseIntError),
+    InvalidBusId,
+    TimestampNotFound,
+    BusIdsNotFound,
+    NoResultFound,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 295);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 1068781);
+    }
+
+    #[test]
+    fn can_solve_part2_example2() {
+        assert_eq!(part2(include_str!("../example_input2")).unwrap(), 54);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part2("939\n7,13,x,x,5q,31").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 10: ParseIntError"));
+        let error = part1("939\n7,0").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: InvalidBusId at `0`");
+    }
+}
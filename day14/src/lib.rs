@@ -0,0 +1,108 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+mod part1;
+mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+/// Line of the initialization program.
+enum Instruction {
+    /// Bits set to `1` and floating bits (`X`) of the mask.
+    Mask {
+        ones: u64,
+        floating: u64,
+    },
+    Write {
+        address: u64,
+        value: u64,
+    },
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError<Error>> {
+    parse_lines(input, |line| {
+        let mut split_line = line.split(" = ");
+        let lhs = split_line.next().unwrap_or(line);
+        let rhs = split_line
+            .next()
+            .ok_or_else(|| ParseError::new(line, &line[line.len()..], Error::RhsNotFound))?;
+        if lhs == "mask" {
+            let (ones, floating) =
+                rhs.char_indices()
+                    .try_fold((0, 0), |(ones, floating), (i, bit)| match bit {
+                        '0' => Ok((ones << 1, floating << 1)),
+                        '1' => Ok((ones << 1 | 1, floating << 1)),
+                        'X' => Ok((ones << 1, floating << 1 | 1)),
+                        _ => Err(ParseError::new(
+                            line,
+                            &rhs[i..i + bit.len_utf8()],
+                            Error::InvalidMaskBit,
+                        )),
+                    })?;
+            return Ok(Instruction::Mask { ones, floating });
+        }
+
+        let address = lhs
+            .strip_prefix("mem[")
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| ParseError::new(line, lhs, Error::UnknownInstruction))?;
+        let parse_number =
+            |s| u64::from_str(s).map_err(|e| ParseError::new(line, s, Error::ParseError(e)));
+        Ok(Instruction::Write {
+            address: parse_number(address)?,
+            value: parse_number(rhs)?,
+        })
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    RhsNotFound,
+    UnknownInstruction,
+    InvalidMaskBit,
+    ParseError(ParseIntError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 165);
+    }
+
+    #[test]
+    fn can_solve_part2_example2() {
+        assert_eq!(part2(include_str!("../example_input2")).unwrap(), 208);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part1("mask = XX1\nmem[8] = 11\nmem[7] = 1o1").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 3, column 10: ParseError"));
+        let error = part2("mask = X21").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 9: InvalidMaskBit at `2`");
+        let error = part2("mem(8) = 11").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: UnknownInstruction at `mem(8)`"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, aoc_common::Error> {
+    spoken_number(input, 2020)
+}
+
+pub fn part2(input: &str) -> Result<u32, aoc_common::Error> {
+    spoken_number(input, 30000000)
+}
+
+fn spoken_number(input: &str, turn: usize) -> Result<u32, aoc_common::Error> {
+    let line = input.trim_end();
+    let starting_numbers = line
+        .split(',')
+        .map(|n| {
+            n.parse()
+                .map_err(|e| ParseError::new(line, n, e).at_line(1))
+        })
+        .collect::<Result<Vec<u32>, ParseError<ParseIntError>>>()?;
+    let later_turns = turn
+        .checked_sub(starting_numbers.len() + 1)
+        .ok_or_else(|| {
+            aoc_common::Error::Solver(format!(
+                "Turn {} is not after the {} starting numbers",
+                turn,
+                starting_numbers.len()
+            ))
+        })?;
+    let mut turn_iterator = TurnIterator::new(&starting_numbers);
+
+    Ok(turn_iterator.nth(later_turns).expect("No next"))
+}
+
+struct TurnIterator {
+    last_turns: HashMap<u32, u32>,
+    turn: u32,
+    last_number: u32,
+}
+
+impl TurnIterator {
+    pub fn new(starting_numbers: &[u32]) -> Self {
+        let mut last_turns = HashMap::new();
+        for (i, &number) in starting_numbers.iter().enumerate() {
+            if i == starting_numbers.len() - 1 {
+                break;
+            }
+
+            last_turns.insert(number, (i + 1) as u32);
+        }
+
+        Self {
+            last_turns,
+            turn: starting_numbers.len() as u32,
+            last_number: starting_numbers[starting_numbers.len() - 1],
+        }
+    }
+}
+
+impl Iterator for TurnIterator {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let l = self.last_number;
+        if let Some(&last) = self.last_turns.get(&self.last_number) {
+            self.last_number = self.turn - last;
+        } else {
+            self.last_number = 0;
+        }
+
+        self.last_turns.insert(l, self.turn);
+        self.turn += 1;
+        Some(self.last_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 436);
+    }
+
+    #[test]
+    fn rejects_turns_among_starting_numbers() {
+        assert_eq!(spoken_number("0,3,6", 4).unwrap(), 0);
+        assert!(spoken_number("0,3,6", 3).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use aoc_common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+use std::str::{FromStr, Lines};
+
+type Rules = HashMap<String, Rule>;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, aoc_common::Error> {
+    let mut notes = Notes::new(input);
+
+    let rules = parse_rules(&mut notes)?;
+    let _ = parse_your_ticket(&mut notes)?;
+    let nearby_tickets = parse_nearby_tickets(&mut notes)?;
+
+    Ok(compute_ticket_scanning_error_rate(&rules, &nearby_tickets))
+}
+
+fn compute_ticket_scanning_error_rate(rules: &Rules, nearby_tickets: &[Ticket]) -> u32 {
+    nearby_tickets.iter().fold(0, |acc, ticket| {
+        acc + compute_ticket_error_count(rules, ticket)
+    })
+}
+
+fn compute_ticket_error_count(rules: &Rules, ticket: &Ticket) -> u32 {
+    ticket.values.iter().fold(0, |acc, &value| {
+        if rules.iter().any(|(_, rule)| validate(value, rule)) {
+            acc
+        } else {
+            acc + value
+        }
+    })
+}
+
+fn validate(value: u32, rule: &Rule) -> bool {
+    rule.valid_ranges.iter().any(|r| r.contains(&value))
+}
+
+fn parse_your_ticket(notes: &mut Notes) -> Result<Ticket, ParseError<Error>> {
+    notes.expect_line("your ticket:", Error::ExpectedYourTicket)?;
+    let ticket = notes.parse_line(parse_ticket)?;
+    notes.expect_line("", Error::ExpectedEmptyLine)?;
+
+    Ok(ticket)
+}
+
+fn parse_nearby_tickets(notes: &mut Notes) -> Result<Vec<Ticket>, ParseError<Error>> {
+    notes.expect_line("nearby tickets:", Error::ExpectedNearbyTickets)?;
+
+    let mut tickets = vec![];
+    while !notes.at_end() {
+        tickets.push(notes.parse_line(parse_ticket)?);
+    }
+
+    Ok(tickets)
+}
+
+fn parse_rules(notes: &mut Notes) -> Result<Rules, ParseError<Error>> {
+    let mut rules: Rules = Rules::new();
+    while let Some(rule) = notes.parse_line(|line| {
+        if line.trim().is_empty() {
+            Ok(None)
+        } else {
+            parse_rule(line).map(Some)
+        }
+    })? {
+        rules.insert(rule.field.clone(), rule);
+    }
+
+    Ok(rules)
+}
+
+/// Lines of the notes, numbered to locate the errors.
+struct Notes<'a> {
+    lines: Peekable<Lines<'a>>,
+    line_number: usize,
+}
+
+impl<'a> Notes<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines().peekable(),
+            line_number: 0,
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.lines.peek().is_none()
+    }
+
+    /// Parses the next line, the errors of `parse` being located on it.
+    fn parse_line<T>(
+        &mut self,
+        parse: impl FnOnce(&'a str) -> Result<T, ParseError<Error>>,
+    ) -> Result<T, ParseError<Error>> {
+        self.line_number += 1;
+        let result = match self.lines.next() {
+            Some(line) => parse(line),
+            None => Err(ParseError::new("", "", Error::UnexpectedEof)),
+        };
+
+        result.map_err(|e| e.at_line(self.line_number))
+    }
+
+    fn expect_line(&mut self, expected: &str, kind: Error) -> Result<(), ParseError<Error>> {
+        self.parse_line(|line| {
+            if line == expected {
+                Ok(())
+            } else {
+                Err(ParseError::new(line, line, kind))
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Ticket {
+    values: Vec<u32>,
+}
+
+fn parse_ticket(line: &str) -> Result<Ticket, ParseError<Error>> {
+    Ok(Ticket {
+        values: line
+            .split(',')
+            .map(|value| {
+                u32::from_str(value)
+                    .map_err(|e| ParseError::new(line, value, Error::ParseValueError(e)))
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+#[derive(Debug)]
+struct Rule {
+    field: String,
+    valid_ranges: Vec<RangeInclusive<u32>>,
+}
+
+fn parse_rule(line: &str) -> Result<Rule, ParseError<Error>> {
+    let mut split_str = line.splitn(2, ':');
+    let field = split_str.next().unwrap_or(line).to_owned();
+
+    let ranges_str = split_str
+        .next()
+        .ok_or_else(|| ParseError::new(line, &line[line.len()..], Error::ExpectedRanges))?;
+    let parse_bound = |bound: Option<&str>, missing| {
+        let bound = bound.ok_or_else(|| ParseError::new(line, ranges_str, missing))?;
+        u32::from_str(bound.trim())
+            .map_err(|e| ParseError::new(line, bound, Error::ParseRangeBoundError(e)))
+    };
+    let mut valid_ranges = Vec::new();
+    for range_str in ranges_str.split(" or ") {
+        let mut split_range_str = range_str.splitn(2, '-');
+        let lower_bound = parse_bound(split_range_str.next(), Error::ExpectedLowerRangeBound)?;
+        let higher_bound = parse_bound(split_range_str.next(), Error::ExpectedHigherRangeBound)?;
+
+        valid_ranges.push(lower_bound..=higher_bound);
+    }
+
+    Ok(Rule {
+        field,
+        valid_ranges,
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ExpectedRanges,
+    ExpectedLowerRangeBound,
+    ExpectedHigherRangeBound,
+    ParseRangeBoundError(ParseIntError),
+    ParseValueError(ParseIntError),
+    ExpectedYourTicket,
+    ExpectedEmptyLine,
+    ExpectedNearbyTickets,
+    UnexpectedEof,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 71);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let input = include_str!("../example_input").replace("7,3,47", "7,3,x47");
+        let error = part1(&input).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 9, column 5: ParseValueError"));
+        let input = include_str!("../example_input").replace("6-11", "6-");
+        let error = part1(&input).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 8: ParseRangeBoundError"));
+        let error = part1("class: 1-3\n\nyour ticket:\n1").unwrap_err();
+        assert_eq!(error.to_string(), "line 5, column 1: UnexpectedEof at ``");
+    }
+}
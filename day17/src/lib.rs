@@ -0,0 +1,54 @@
+use aoc_common::{Automaton, Grid, Neighbourhood, Solution};
+
+const RULE: &str = "B3/S23";
+const CYCLES: usize = 6;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    active_cubes_after_boot::<3>(input)
+}
+
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    active_cubes_after_boot::<4>(input)
+}
+
+/// The input is the `z = 0` slice of the pocket dimension, other coordinates being 0 too.
+fn active_cubes_after_boot<const N: usize>(input: &str) -> Result<usize, aoc_common::Error> {
+    let slice: Grid<bool> = input.trim().parse()?;
+    let mut pocket_dimension = Automaton::<N>::new(Neighbourhood::Moore, RULE.parse()?)?;
+    for ((x, y), _) in slice.iter().filter(|(_, &active)| active) {
+        let mut cube = [0; N];
+        cube[0] = x as i32;
+        cube[1] = y as i32;
+        pocket_dimension.set_alive(cube, true);
+    }
+
+    pocket_dimension.run(CYCLES);
+    Ok(pocket_dimension.live_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 112);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 848);
+    }
+}
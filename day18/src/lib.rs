@@ -0,0 +1,236 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::iter::Peekable;
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Token {
+    Number(i64),
+    Operator(OperatorType),
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum OperatorType {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+}
+
+impl OperatorType {
+    fn apply(self, lhs: i64, rhs: i64) -> Result<i64, Error> {
+        match self {
+            OperatorType::Plus => lhs.checked_add(rhs).ok_or(Error::Overflow),
+            OperatorType::Minus => lhs.checked_sub(rhs).ok_or(Error::Overflow),
+            OperatorType::Multiply => lhs.checked_mul(rhs).ok_or(Error::Overflow),
+            OperatorType::Divide if rhs == 0 => Err(Error::DivisionByZero),
+            OperatorType::Divide => lhs.checked_div(rhs).ok_or(Error::Overflow),
+        }
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part2(input: &str) -> Result<i64, aoc_common::Error> {
+    Ok(parse_lines(input, evaluate_line)?
+        .into_iter()
+        .try_fold(0i64, |acc, value| acc.checked_add(value))
+        .ok_or(Error::Overflow)?)
+}
+
+/// Evaluates an expression where `+` and `-` take precedence over `*` and `/`.
+fn evaluate_line(line: &str) -> Result<i64, ParseError<Error>> {
+    let mut parser = Parser {
+        line,
+        tokens: tokenize(line)?.into_iter().peekable(),
+    };
+    let value = parser.expression()?;
+    match parser.tokens.next() {
+        None => Ok(value),
+        token => Err(parser.unexpected(token)),
+    }
+}
+
+/// Recursive descent parser over the tokens of a line, each token being kept
+/// with its text to locate errors.
+struct Parser<'a> {
+    line: &'a str,
+    tokens: Peekable<IntoIter<(&'a str, Token)>>,
+}
+
+impl<'a> Parser<'a> {
+    fn expression(&mut self) -> Result<i64, ParseError<Error>> {
+        let mut result = self.term()?;
+        while let Some((text, operator)) =
+            self.next_operator(&[OperatorType::Multiply, OperatorType::Divide])
+        {
+            let rhs = self.term()?;
+            result = operator
+                .apply(result, rhs)
+                .map_err(|e| ParseError::new(self.line, text, e))?;
+        }
+
+        Ok(result)
+    }
+
+    fn term(&mut self) -> Result<i64, ParseError<Error>> {
+        let mut result = self.factor()?;
+        while let Some((text, operator)) =
+            self.next_operator(&[OperatorType::Plus, OperatorType::Minus])
+        {
+            let rhs = self.factor()?;
+            result = operator
+                .apply(result, rhs)
+                .map_err(|e| ParseError::new(self.line, text, e))?;
+        }
+
+        Ok(result)
+    }
+
+    fn factor(&mut self) -> Result<i64, ParseError<Error>> {
+        match self.tokens.next() {
+            Some((_, Token::Number(value))) => Ok(value),
+            Some((_, Token::LeftParenthesis)) => {
+                let value = self.expression()?;
+                match self.tokens.next() {
+                    Some((_, Token::RightParenthesis)) => Ok(value),
+                    token => Err(self.unexpected(token)),
+                }
+            }
+            token => Err(self.unexpected(token)),
+        }
+    }
+
+    fn next_operator(&mut self, operators: &[OperatorType]) -> Option<(&'a str, OperatorType)> {
+        match self.tokens.peek() {
+            Some(&(text, Token::Operator(operator))) if operators.contains(&operator) => {
+                self.tokens.next();
+                Some((text, operator))
+            }
+            _ => None,
+        }
+    }
+
+    fn unexpected(&self, token: Option<(&'a str, Token)>) -> ParseError<Error> {
+        match token {
+            Some((text, _)) => ParseError::new(self.line, text, Error::UnexpectedToken),
+            None => ParseError::new(
+                self.line,
+                &self.line[self.line.len()..],
+                Error::UnexpectedEndOfLine,
+            ),
+        }
+    }
+}
+
+fn tokenize(line: &str) -> Result<Vec<(&str, Token)>, ParseError<Error>> {
+    let mut tokens = vec![];
+    let mut characters = line.char_indices().peekable();
+    while let Some((start, character)) = characters.next() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        let mut end = start + character.len_utf8();
+        if character.is_ascii_digit() {
+            while let Some(&(i, c)) = characters.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                end = i + 1;
+                characters.next();
+            }
+        }
+
+        let text = &line[start..end];
+        tokens.push((
+            text,
+            text.parse().map_err(|e| ParseError::new(line, text, e))?,
+        ));
+    }
+
+    Ok(tokens)
+}
+
+impl FromStr for Token {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i64>() {
+            Ok(number) => Self::Number(number),
+            _ => match s {
+                "+" => Self::Operator(OperatorType::Plus),
+                "-" => Self::Operator(OperatorType::Minus),
+                "*" => Self::Operator(OperatorType::Multiply),
+                "/" => Self::Operator(OperatorType::Divide),
+                "(" => Self::LeftParenthesis,
+                ")" => Self::RightParenthesis,
+                _ => return Err(Error::UnknownToken(s.into())),
+            },
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    UnknownToken(String),
+    UnexpectedToken,
+    UnexpectedEndOfLine,
+    DivisionByZero,
+    Overflow,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part2_example() {
+        let expected = [51, 46, 1445, 669060, 23340];
+        for (line, expected) in include_str!("../example_input").lines().zip(&expected) {
+            assert_eq!(part2(line).unwrap(), *expected);
+        }
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 693942);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part2("1 + 2\n1 + (2 * 3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: UnexpectedEndOfLine at ``"
+        );
+        let error = part2("2 * 3 )").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: UnexpectedToken at `)`"
+        );
+        let error = part2("2 * x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: UnknownToken(\"x\") at `x`"
+        );
+        let error = part2("4 / (1 - 1)").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 3: DivisionByZero at `/`");
+    }
+
+    #[test]
+    fn reports_sum_overflow() {
+        let input = format!("{}\n1", i64::MAX);
+        assert_eq!(part2(&input).unwrap_err().to_string(), "Overflow");
+    }
+}
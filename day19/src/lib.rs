@@ -0,0 +1,205 @@
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    count_matching_messages(input, &[])
+}
+
+/// Rules 8 and 11 are replaced by their looping version, the regex generation
+/// stops recursing deep enough for these loops to be unrolled.
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    count_matching_messages(input, &["8: 42 | 42 8", "11: 42 31 | 42 11 31"])
+}
+
+fn count_matching_messages(
+    input: &str,
+    overridden_rules: &[&str],
+) -> Result<usize, aoc_common::Error> {
+    let mut lines = input.lines().enumerate();
+
+    let mut rules = Rules::new();
+    for (i, rule) in lines.by_ref() {
+        if rule.is_empty() {
+            break;
+        }
+        let rule = parse_rule(rule).map_err(|e| e.at_line(i + 1))?;
+        rules.insert(rule.identifier, rule);
+    }
+
+    for rule in overridden_rules {
+        let rule = parse_rule(rule)?;
+        rules.insert(rule.identifier, rule);
+    }
+
+    let regex_string = generate_regex_for_rule(0, 0, &rules)?;
+    let regex =
+        Regex::new(&format!("{}{}{}", "^", &regex_string, "$")).map_err(Error::InvalidRegex)?;
+    Ok(lines.filter(|(_, str)| regex.is_match(str)).count())
+}
+
+type Rules = HashMap<usize, Rule>;
+
+fn generate_regex_for_rule(n: usize, rule_index: usize, rules: &Rules) -> Result<String, Error> {
+    let rule = rules
+        .get(&rule_index)
+        .ok_or(Error::UnknownRule(rule_index))?;
+    let mut regex_string = String::new();
+    for (i, definition) in rule.definitions.iter().enumerate() {
+        regex_string += generate_regex_for_definition(n + 1, definition, rules)?.as_str();
+        if i < rule.definitions.len() - 1 {
+            regex_string += "|"
+        }
+    }
+
+    Ok(regex_string)
+}
+
+fn generate_regex_for_definition(
+    n: usize,
+    definition: &RuleDefinition,
+    rules: &Rules,
+) -> Result<String, Error> {
+    match definition {
+        RuleDefinition::TerminalRule(c) => Ok(regex::escape(&c.to_string())),
+        RuleDefinition::RuleSequence(s) => s.iter().try_fold(String::new(), |acc, i| {
+            let mut result = String::new();
+
+            if n < 15 {
+                result = format!("{}{}{}", "(", &*generate_regex_for_rule(n, *i, rules)?, ")")
+            }
+
+            Ok(acc + &*result)
+        }),
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    identifier: usize,
+    definitions: Vec<RuleDefinition>,
+}
+
+fn parse_rule(line: &str) -> Result<Rule, ParseError<Error>> {
+    let mut split_string = line.splitn(2, ':');
+    let identifier = split_string.next().unwrap_or(line);
+    let identifier = usize::from_str(identifier)
+        .map_err(|e| ParseError::new(line, identifier, Error::RuleIdentifierParseError(e)))?;
+
+    let rule_definitions = split_string
+        .next()
+        .ok_or_else(|| ParseError::new(line, &line[line.len()..], Error::RuleDefinitionNotFound))?;
+    let definitions = rule_definitions
+        .split('|')
+        .map(|definition| parse_rule_definition(line, definition))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Rule {
+        identifier,
+        definitions,
+    })
+}
+
+#[derive(Debug)]
+enum RuleDefinition {
+    RuleSequence(Vec<usize>),
+    TerminalRule(char),
+}
+
+/// `definition` is an alternative of the rule defined by `line`.
+fn parse_rule_definition(
+    line: &str,
+    definition: &str,
+) -> Result<RuleDefinition, ParseError<Error>> {
+    let fragments: Vec<&str> = definition.split_whitespace().collect();
+    match fragments[..] {
+        [] => Err(ParseError::new(
+            line,
+            definition,
+            Error::RuleDefinitionFragmentNotFound,
+        )),
+        [fragment] if fragment.starts_with('"') => {
+            let mut characters = fragment[1..].chars();
+            match (characters.next(), characters.as_str()) {
+                (Some(c), "\"") if c != '"' => Ok(RuleDefinition::TerminalRule(c)),
+                _ => Err(ParseError::new(line, fragment, Error::InvalidTerminal)),
+            }
+        }
+        _ => fragments
+            .iter()
+            .map(|&fragment| {
+                usize::from_str(fragment).map_err(|e| {
+                    ParseError::new(line, fragment, Error::RuleDefinitionFragmentParseError(e))
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(RuleDefinition::RuleSequence),
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    RuleIdentifierParseError(ParseIntError),
+    RuleDefinitionNotFound,
+    RuleDefinitionFragmentNotFound,
+    RuleDefinitionFragmentParseError(ParseIntError),
+    InvalidTerminal,
+    /// Rule referenced without being defined.
+    UnknownRule(usize),
+    InvalidRegex(regex::Error),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 2);
+    }
+
+    #[test]
+    fn can_solve_part2_example2() {
+        assert_eq!(part2(include_str!("../example_input2")).unwrap(), 12);
+    }
+
+    #[test]
+    fn can_solve_part1_input2() {
+        assert_eq!(part1(include_str!("../input2")).unwrap(), 422);
+    }
+
+    #[test]
+    fn reports_rule_errors() {
+        let error = part1("0: 1 2\n1: \"a\"\n\nab").unwrap_err();
+        assert_eq!(error.to_string(), "UnknownRule(2)");
+        let error = part1("0: 1 | 1 x\n1: \"a\"\n\na").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 10: RuleDefinitionFragmentParseError"));
+        let error = part1("0: 1\n1: 1 |\n\na").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: RuleDefinitionFragmentNotFound at ``"
+        );
+    }
+}
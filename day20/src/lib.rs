@@ -0,0 +1,177 @@
+use aoc_common::{split_records, Grid, ParseError, ParseGridError, Record, Solution};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    let pieces = parse_tiles(input)?;
+    let corners = pieces.iter().fold(vec![], |mut acc, piece| {
+        if is_corner(piece, &pieces) {
+            acc.push(piece);
+        }
+        acc
+    });
+
+    Ok(corners.iter().fold(1usize, |acc, c| c.id as usize * acc))
+}
+
+fn is_corner(piece: &Piece, pieces: &[Piece]) -> bool {
+    pieces
+        .iter()
+        .filter(|p| p.id != piece.id && match_piece(piece, p))
+        .count()
+        == 2
+}
+
+fn match_piece(piece: &Piece, other: &Piece) -> bool {
+    other.data.orientations().iter().any(|other| {
+        [Side::Right, Side::Left, Side::Top, Side::Bottom]
+            .iter()
+            .any(|side| piece.match_side(other, side))
+    })
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Piece>, ParseError<Error>> {
+    split_records(input)
+        .map(|record| parse_tile(&record))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct Piece {
+    id: i32,
+    data: Grid<bool>,
+}
+
+impl Piece {
+    fn match_side(&self, other: &Grid<bool>, side: &Side) -> bool {
+        side.edge(&self.data) == side.opposite().edge(other)
+    }
+}
+
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    fn edge(&self, data: &Grid<bool>) -> Vec<bool> {
+        match self {
+            Side::Top => data.row(0).to_vec(),
+            Side::Bottom => data.row(data.height() - 1).to_vec(),
+            Side::Left => data.column(0),
+            Side::Right => data.column(data.width() - 1),
+        }
+    }
+
+    fn opposite(&self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// `Tile N:` line followed by the rows of the tile.
+fn parse_tile(record: &Record) -> Result<Piece, ParseError<Error>> {
+    let (header, rows) = record.lines.split_first().ok_or_else(|| {
+        ParseError::new("", "", Error::IdentifierRowNotFound).at_line(record.first_line)
+    })?;
+    let id = header
+        .strip_prefix("Tile ")
+        .and_then(|header| header.strip_suffix(':'))
+        .ok_or_else(|| {
+            ParseError::new(header, header, Error::IdentifierNotFound).at_line(record.first_line)
+        })?;
+    let id = i32::from_str(id).map_err(|e| {
+        ParseError::new(header, id, Error::IdentifierParseError(e)).at_line(record.first_line)
+    })?;
+
+    let data = rows.join("\n").parse().map_err(|e| {
+        let (row, column, text) = match e {
+            ParseGridError::InvalidCell(row, column, cell) => (row, column, cell.to_string()),
+            ParseGridError::UnevenRow(row) => (row, 1, rows[row - 1].clone()),
+            ParseGridError::Empty => (0, 1, String::new()),
+        };
+        ParseError {
+            line: record.first_line + row,
+            column,
+            text,
+            kind: Error::DataParseError(e),
+        }
+    })?;
+
+    Ok(Piece { id, data })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IdentifierRowNotFound,
+    IdentifierNotFound,
+    IdentifierParseError(ParseIntError),
+    DataParseError(ParseGridError),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_match_side() {
+        let mut data = Grid::filled(10, 10, false);
+        *data.get_mut(9, 2).unwrap() = true;
+        *data.get_mut(9, 5).unwrap() = true;
+
+        let first_piece = Piece { id: 0, data };
+
+        let mut second_piece = first_piece.clone();
+        second_piece.data = second_piece
+            .data
+            .flipped_x()
+            .rotated()
+            .rotated()
+            .rotated()
+            .rotated();
+        second_piece.id = 1;
+
+        assert!(first_piece.match_side(&second_piece.data, &Side::Right));
+    }
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(
+            part1(include_str!("../example_input")).unwrap(),
+            20899048083289
+        );
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part1("Tile 1:\n#.\n.#\n\nTile x2:\n#.\n.#").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 5, column 6: IdentifierParseError"));
+        let error = part1("Tile 1:\n#.\n.#\n\nTile 2:\n#.\n.o").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 2: DataParseError(InvalidCell(2, 2, 'o')) at `o`"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        part2(input)
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32, aoc_common::Error> {
+    let foods = parse_foods(input)?;
+    let allergens_ingredients = find_allergens_ingredients(&foods)?;
+
+    let all_ingredients = foods.iter().fold(Ingredients::new(), |acc, f| {
+        acc.union(&f.ingredients).cloned().collect()
+    });
+    let non_allergen_ingredients: Ingredients = all_ingredients
+        .difference(
+            &allergens_ingredients
+                .iter()
+                .fold(HashSet::new(), |mut acc, v| {
+                    acc.insert(v.1.iter().next().unwrap().clone());
+                    acc
+                }),
+        )
+        .cloned()
+        .collect();
+    Ok(foods.iter().fold(0, |acc, f| {
+        acc + non_allergen_ingredients
+            .iter()
+            .fold(0, |acc, i| acc + f.ingredients.contains(i) as i32)
+    }))
+}
+
+pub fn part2(input: &str) -> Result<String, aoc_common::Error> {
+    let foods = parse_foods(input)?;
+    let allergens_ingredients = find_allergens_ingredients(&foods)?;
+
+    let mut sorted_allergens_ingredient: Vec<_> = allergens_ingredients.into_iter().collect();
+    sorted_allergens_ingredient.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(sorted_allergens_ingredient
+        .iter()
+        .map(|a| a.1.iter().next().unwrap().clone())
+        .collect::<Vec<String>>()
+        .join(","))
+}
+
+fn parse_foods(input: &str) -> Result<Vec<Food>, ParseError<Error>> {
+    parse_lines(input, parse_food)
+}
+
+fn find_allergens_ingredients(foods: &[Food]) -> Result<HashMap<String, HashSet<String>>, Error> {
+    let all_allergens = foods.iter().fold(Allergens::new(), |acc, f| {
+        acc.union(&f.allergens).cloned().collect()
+    });
+
+    let mut allergens_ingredients: HashMap<String, HashSet<String>> = HashMap::new();
+    for allergen in &all_allergens {
+        let mut foods_containing_allergens =
+            foods.iter().filter(|f| f.allergens.contains(allergen));
+        let mut ingredients = foods_containing_allergens
+            .next()
+            .unwrap()
+            .ingredients
+            .clone();
+        for f in foods_containing_allergens {
+            ingredients = ingredients.intersection(&f.ingredients).cloned().collect();
+        }
+
+        allergens_ingredients.insert(allergen.clone(), ingredients);
+    }
+
+    while allergens_ingredients
+        .iter()
+        .any(|(_, ingredients)| ingredients.len() != 1)
+    {
+        let mut removed = false;
+        let unique_ingredients: Vec<String> = allergens_ingredients
+            .iter()
+            .filter(|(_, ingredients)| ingredients.len() == 1)
+            .map(|f| f.1.iter().next().unwrap())
+            .cloned()
+            .collect();
+
+        for (_, is) in allergens_ingredients.iter_mut() {
+            for unique_ingredient in &unique_ingredients {
+                if is.len() > 1 && is.remove(unique_ingredient) {
+                    removed = true;
+                }
+            }
+        }
+
+        if !removed {
+            let mut unresolved: Vec<String> = allergens_ingredients
+                .into_iter()
+                .filter(|(_, ingredients)| ingredients.len() != 1)
+                .map(|(allergen, _)| allergen)
+                .collect();
+            unresolved.sort();
+            return Err(Error::UnresolvedAllergens(unresolved));
+        }
+    }
+
+    Ok(allergens_ingredients)
+}
+
+type Ingredients = HashSet<String>;
+type Allergens = HashSet<String>;
+#[derive(Debug)]
+struct Food {
+    ingredients: Ingredients,
+    allergens: Allergens,
+}
+
+/// `ingredient... (contains allergen, ...)`, the allergens being optional.
+fn parse_food(line: &str) -> Result<Food, ParseError<Error>> {
+    const CONTAINS: &str = " (contains ";
+    let (ingredients, allergens) = match line.find(CONTAINS) {
+        Some(i) => (
+            &line[..i],
+            Some(
+                line[i + CONTAINS.len()..]
+                    .strip_suffix(')')
+                    .ok_or_else(|| {
+                        ParseError::new(
+                            line,
+                            &line[line.len()..],
+                            Error::ClosingParenthesisNotFound,
+                        )
+                    })?,
+            ),
+        ),
+        None => (line, None),
+    };
+
+    let words = |words: &str, separator, kind: fn(String) -> Error| {
+        words
+            .split(separator)
+            .map(|word| {
+                if !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()) {
+                    Ok(word.to_owned())
+                } else {
+                    Err(ParseError::new(line, word, kind(word.into())))
+                }
+            })
+            .collect::<Result<HashSet<_>, _>>()
+    };
+    Ok(Food {
+        ingredients: words(ingredients, " ", Error::InvalidIngredient)?,
+        allergens: match allergens {
+            Some(allergens) => words(allergens, ", ", Error::InvalidAllergen)?,
+            None => Allergens::new(),
+        },
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ClosingParenthesisNotFound,
+    InvalidIngredient(String),
+    InvalidAllergen(String),
+    /// Allergens whose ingredient cannot be told apart from the others.
+    UnresolvedAllergens(Vec<String>),
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 5);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(
+            part2(include_str!("../example_input")).unwrap(),
+            "mxmxvkd,sqjhc,fvjkl"
+        );
+    }
+
+    #[test]
+    fn reports_malformed_foods() {
+        let error = part1("mxmxvkd kfcds (contains dairy, fish").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 36: ClosingParenthesisNotFound at ``"
+        );
+        let error = part1("sqjhc fvjkl (contains soy)\nsqjhc  fvjkl").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: InvalidIngredient(\"\") at ``"
+        );
+    }
+
+    #[test]
+    fn reports_unresolved_allergens() {
+        let error = part2("mxmxvkd kfcds (contains dairy, fish)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "UnresolvedAllergens([\"dairy\", \"fish\"])"
+        );
+    }
+}
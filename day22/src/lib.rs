@@ -1,41 +1,85 @@
+use aoc_common::{split_records, trace, ParseError, Solution};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::VecDeque;
 use std::fmt::{Display, Formatter};
-use std::fs::File;
 use std::hash::{Hash, Hasher};
-use std::io::{BufRead, BufReader};
+use std::num::ParseIntError;
 use std::str::FromStr;
 
-fn main() -> Result<(), Error> {
-    let file = File::open("input").map_err(|e| Error::InputFileOpenError(e))?;
-    let reader = BufReader::new(file);
-    let decks = parse_decks(reader)?;
-    let mut game = RecursiveCombatGame::with_decks(decks, 1);
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, aoc_common::Error> {
+    let decks = parse_decks(input)?;
+    let mut game = CombatGame::with_decks(decks);
     while let GameState::Continue = game.state() {
         game.play_round()?;
     }
     game.print_post_game_results();
 
-    Ok(())
+    Ok(game.winner_score().ok_or(Error::NoWinner)?)
 }
 
-fn parse_decks(reader: impl BufRead) -> Result<Vec<Deck>, Error> {
-    Ok(reader.lines().try_fold(vec![], |mut acc, line| {
-        let line = line.map_err(|e| Error::InputFileReadError(e))?;
+pub fn part2(input: &str) -> Result<u64, aoc_common::Error> {
+    let decks = parse_decks(input)?;
+    let mut game = RecursiveCombatGame::with_decks(decks, 1);
+    while let GameState::Continue = game.state() {
+        game.play_round()?;
+    }
+    game.print_post_game_results();
 
-        if line.is_empty() {
-            return Ok(acc);
-        }
+    Ok(game.winner_score().ok_or(Error::NoWinner)?)
+}
 
-        if line.starts_with("Player") {
-            acc.push(Deck(VecDeque::new()));
-            return Ok(acc);
-        }
+const PLAYER_COUNT: usize = 2;
+
+/// Decks of the two players separated by blank lines, each starting with a
+/// `Player N:` line.
+fn parse_decks(input: &str) -> Result<Vec<Deck>, ParseError<Error>> {
+    let decks = split_records(input)
+        .enumerate()
+        .map(|(player, record)| {
+            let mut lines = record.numbered_lines();
+            let (number, header) = lines.next().unwrap_or((record.first_line, ""));
+            if player >= PLAYER_COUNT {
+                return Err(ParseError::new(header, header, Error::UnexpectedDeck).at_line(number));
+            }
+            let is_header = header
+                .strip_prefix("Player ")
+                .and_then(|player| player.strip_suffix(':'))
+                .is_some_and(|player| player.parse::<usize>().is_ok());
+            if !is_header {
+                return Err(
+                    ParseError::new(header, header, Error::DeckHeaderNotFound).at_line(number)
+                );
+            }
 
-        let deck: &mut Deck = acc.last_mut().ok_or(Error::LastDeckNotFound)?;
-        deck.0.push_back(line.parse()?);
-        Ok(acc)
-    })?)
+            Ok(Deck(
+                lines
+                    .map(|(number, card)| {
+                        u8::from_str(card).map(Card).map_err(|e| {
+                            ParseError::new(card, card, Error::ParseCardError(e)).at_line(number)
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if decks.len() < PLAYER_COUNT {
+        return Err(ParseError::new("", "", Error::MissingDeck).at_line(input.lines().count() + 1));
+    }
+
+    Ok(decks)
 }
 
 struct RecursiveCombatGame {
@@ -74,18 +118,17 @@ impl RecursiveCombatGame {
                 .pop_front()
                 .ok_or(Error::PlayerHasNoCardToPlay)?;
 
-            println!("Player {} plays: {}", player + 1, played_card);
+            trace!("Player {} plays: {}", player + 1, played_card);
             played_cards.push((player, played_card));
         }
 
-        let mut winning_player = 0;
-        if self
+        let winning_player = if self
             .players_decks
             .iter()
             .enumerate()
             .all(|(i, d)| d.0.len() >= played_cards[i].1 .0 as usize)
         {
-            println!("\nPlaying a sub-game to determine the winner...");
+            trace!("\nPlaying a sub-game to determine the winner...");
             let mut decks = self.players_decks.clone();
             for deck in 0..decks.len() {
                 let card_count = played_cards[deck].1 .0;
@@ -95,25 +138,26 @@ impl RecursiveCombatGame {
             }
 
             let mut sub_game = RecursiveCombatGame::with_decks(decks, self.game_number + 1);
-            winning_player = loop {
-                sub_game.play_round();
+            let winning_player = loop {
+                sub_game.play_round()?;
                 if let GameState::GameOver(winner) = sub_game.state() {
                     break winner;
                 }
             };
-            println!("...Anyway back to game {}", self.game_number);
+            trace!("...Anyway back to game {}", self.game_number);
+            winning_player
         } else {
-            winning_player = played_cards
+            played_cards
                 .iter()
                 .enumerate()
                 .fold(
                     (0, 0),
                     |acc, (i, c)| if acc.1 < c.1 .0 { (i, c.1 .0) } else { acc },
                 )
-                .0;
-        }
+                .0
+        };
 
-        println!(
+        trace!(
             "Player {} wins round {} of game {}!\n",
             winning_player + 1,
             self.round,
@@ -142,10 +186,17 @@ impl RecursiveCombatGame {
         self.state
     }
 
+    pub fn winner_score(&self) -> Option<u64> {
+        match self.state {
+            GameState::GameOver(winner) => Some(self.players_decks[winner].score()),
+            GameState::Continue => None,
+        }
+    }
+
     pub fn print_round_infos(&self) {
-        println!("-- Round {} (Game {}) --", self.round, self.game_number);
+        trace!("-- Round {} (Game {}) --", self.round, self.game_number);
         for player in 0..self.players_decks.len() {
-            println!(
+            trace!(
                 "Player {}'s deck: {}",
                 player + 1,
                 self.players_decks[player]
@@ -154,24 +205,16 @@ impl RecursiveCombatGame {
     }
 
     pub fn print_post_game_results(&self) {
-        println!("== Post-game results ==");
+        trace!("== Post-game results ==");
         for player in 0..self.players_decks.len() {
-            println!(
+            trace!(
                 "Player {}'s deck: {}",
                 player + 1,
                 self.players_decks[player]
             );
 
             if !self.players_decks[player].0.is_empty() {
-                println!(
-                    "score: {}",
-                    self.players_decks[player]
-                        .0
-                        .iter()
-                        .rev()
-                        .enumerate()
-                        .fold(0u64, |acc, (i, c)| { acc + (i as u64 + 1) * c.0 as u64 })
-                );
+                trace!("score: {}", self.players_decks[player].score());
             }
         }
     }
@@ -202,13 +245,13 @@ impl CombatGame {
                 .pop_front()
                 .ok_or(Error::PlayerHasNoCardToPlay)?;
 
-            println!("Player {} plays: {}", player + 1, played_card);
+            trace!("Player {} plays: {}", player + 1, played_card);
             played_cards.push((player, played_card));
         }
 
         played_cards.sort_by_key(|(_, card)| card.0);
         let winning = &played_cards[played_cards.len() - 1];
-        println!("Player {} wins the round!\n", winning.0 + 1);
+        trace!("Player {} wins the round!\n", winning.0 + 1);
 
         self.players_decks[winning.0]
             .0
@@ -218,7 +261,7 @@ impl CombatGame {
 
         for (player, player_deck) in self.players_decks.iter().enumerate() {
             if player_deck.0.is_empty() {
-                self.state = GameState::GameOver(player)
+                self.state = GameState::GameOver((player + 1) % 2)
             }
         }
 
@@ -229,10 +272,17 @@ impl CombatGame {
         self.state
     }
 
+    pub fn winner_score(&self) -> Option<u64> {
+        match self.state {
+            GameState::GameOver(winner) => Some(self.players_decks[winner].score()),
+            GameState::Continue => None,
+        }
+    }
+
     pub fn print_round_infos(&self) {
-        println!("-- Round {} --", self.round);
+        trace!("-- Round {} --", self.round);
         for player in 0..self.players_decks.len() {
-            println!(
+            trace!(
                 "Player {}'s deck: {}",
                 player + 1,
                 self.players_decks[player]
@@ -241,24 +291,16 @@ impl CombatGame {
     }
 
     pub fn print_post_game_results(&self) {
-        println!("== Post-game results ==");
+        trace!("== Post-game results ==");
         for player in 0..self.players_decks.len() {
-            println!(
+            trace!(
                 "Player {}'s deck: {}",
                 player + 1,
                 self.players_decks[player]
             );
 
             if !self.players_decks[player].0.is_empty() {
-                println!(
-                    "score: {}",
-                    self.players_decks[player]
-                        .0
-                        .iter()
-                        .rev()
-                        .enumerate()
-                        .fold(0, |acc, (i, c)| { acc + (i as u16 + 1) * c.0 as u16 })
-                );
+                trace!("score: {}", self.players_decks[player].score());
             }
         }
     }
@@ -279,17 +321,19 @@ impl Display for Card {
     }
 }
 
-impl FromStr for Card {
-    type Err = Error;
+#[derive(Debug, Hash, Clone)]
+struct Deck(VecDeque<Card>);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Card(u8::from_str(s).map_err(|e| Error::ParseCardError(e))?))
+impl Deck {
+    pub fn score(&self) -> u64 {
+        self.0
+            .iter()
+            .rev()
+            .enumerate()
+            .fold(0u64, |acc, (i, c)| acc + (i as u64 + 1) * c.0 as u64)
     }
 }
 
-#[derive(Debug, Hash, Clone)]
-struct Deck(VecDeque<Card>);
-
 impl Display for Deck {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -305,10 +349,62 @@ impl Display for Deck {
 }
 
 #[derive(Debug)]
-enum Error {
-    InputFileOpenError(std::io::Error),
-    InputFileReadError(std::io::Error),
-    ParseCardError(std::num::ParseIntError),
-    LastDeckNotFound,
+pub enum Error {
+    ParseCardError(ParseIntError),
+    DeckHeaderNotFound,
+    /// The input ended before the deck of every player.
+    MissingDeck,
+    /// A deck after the ones of every player.
+    UnexpectedDeck,
     PlayerHasNoCardToPlay,
+    NoWinner,
+}
+
+impl From<Error> for aoc_common::Error {
+    fn from(error: Error) -> Self {
+        aoc_common::Error::solver(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 306);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 291);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let input = include_str!("../example_input").replace("10", "1O");
+        let error = part1(&input).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 13, column 1: ParseCardError"));
+        let error = part1("Player 1:\n9\n\nPlayer2\n5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: DeckHeaderNotFound at `Player2`"
+        );
+    }
+
+    #[test]
+    fn requires_two_decks() {
+        let error = part1("").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: MissingDeck at ``");
+        let error = part2("Player 1:\n9\n2").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 1: MissingDeck at ``");
+        let input = format!("{}\n\nPlayer 3:\n1", include_str!("../example_input"));
+        let error = part1(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 16, column 1: UnexpectedDeck at `Player 3:`"
+        );
+    }
 }
@@ -0,0 +1,167 @@
+use aoc_common::{ParseError, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, aoc_common::Error> {
+    let labels = parse_labels(input)?;
+    let mut cups = Cups::new(labels.clone(), labels[0]);
+
+    for _move in 0..100 {
+        cups.execute_move();
+    }
+
+    let mut labels_after_1 = String::new();
+    let mut cur = 0;
+    for _ in 0..cups.next_cups.len() - 1 {
+        cur = cups.next_cups[cur];
+        labels_after_1 += &(cur + 1).to_string();
+    }
+
+    Ok(labels_after_1)
+}
+
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    let labels = parse_labels(input)?;
+    let mut vec = labels.clone();
+    for i in 0..1_000_000 - labels.len() {
+        vec.push(i + labels.len() + 1);
+    }
+
+    let mut cups = Cups::new(vec, labels[0]);
+    for _move in 0..10_000_000 {
+        cups.execute_move();
+    }
+
+    let a = cups.next_cups[0];
+    let b = cups.next_cups[a];
+    Ok((a + 1) * (b + 1))
+}
+
+/// Labels of the cups, which have to be every number from 1 to the number of
+/// cups, at least 5 cups being needed for a move to find its destination.
+fn parse_labels(input: &str) -> Result<Vec<usize>, ParseError<Error>> {
+    let line = input.trim();
+    let mut seen = [false; 10];
+    let labels = line
+        .char_indices()
+        .map(|(i, c)| {
+            let label = &line[i..i + c.len_utf8()];
+            match c.to_digit(10) {
+                Some(0) | None => Err(ParseError::new(line, label, Error::InvalidLabel(c))),
+                Some(d) if seen[d as usize] => Err(ParseError::new(
+                    line,
+                    label,
+                    Error::DuplicateLabel(d as usize),
+                )),
+                Some(d) => {
+                    seen[d as usize] = true;
+                    Ok(d as usize)
+                }
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.at_line(1))?;
+
+    let end = &line[line.len()..];
+    if labels.len() < 5 {
+        return Err(ParseError::new(line, end, Error::NotEnoughCups(labels.len())).at_line(1));
+    }
+    if let Some(missing) = (1..=labels.len()).find(|&label| !seen[label]) {
+        return Err(ParseError::new(line, end, Error::MissingLabel(missing)).at_line(1));
+    }
+
+    Ok(labels)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidLabel(char),
+    DuplicateLabel(usize),
+    /// Label between 1 and the number of cups which no cup has.
+    MissingLabel(usize),
+    NotEnoughCups(usize),
+}
+
+struct Cups {
+    next_cups: Vec<usize>,
+    current_cup_label: usize,
+}
+
+impl Cups {
+    pub fn new(cups: Vec<usize>, first_label: usize) -> Self {
+        let mut next_cups = vec![0; cups.len()];
+        for (a, b) in cups.iter().cycle().zip(cups.iter().cycle().skip(1)) {
+            if next_cups[a - 1] != 0 {
+                break;
+            }
+            next_cups[a - 1] = b - 1;
+        }
+
+        Self {
+            next_cups,
+            current_cup_label: first_label - 1,
+        }
+    }
+
+    pub fn execute_move(&mut self) {
+        let (a, b, c) = self.pick_next_3();
+
+        self.next_cups[self.current_cup_label] = self.next_cups[c];
+        let mut destination_cup =
+            (self.next_cups.len() + self.current_cup_label - 1) % self.next_cups.len();
+        while [a, b, c].contains(&destination_cup) {
+            destination_cup = (self.next_cups.len() + destination_cup - 1) % self.next_cups.len();
+        }
+
+        self.next_cups[c] = self.next_cups[destination_cup];
+        self.next_cups[destination_cup] = a;
+        self.current_cup_label = self.next_cups[self.current_cup_label];
+    }
+
+    fn pick_next_3(&self) -> (usize, usize, usize) {
+        let a = self.next_cups[self.current_cup_label];
+        let b = self.next_cups[a];
+        let c = self.next_cups[b];
+        (a, b, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), "67384529");
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(
+            part2(include_str!("../example_input")).unwrap(),
+            149245887792
+        );
+    }
+
+    #[test]
+    fn reports_invalid_labels() {
+        let error = parse_labels("3891254x7").unwrap_err();
+        assert_eq!((error.column, error.kind), (8, Error::InvalidLabel('x')));
+        let error = parse_labels("389125437").unwrap_err();
+        assert_eq!((error.column, error.kind), (8, Error::DuplicateLabel(3)));
+        let error = parse_labels("38915").unwrap_err();
+        assert_eq!((error.column, error.kind), (6, Error::MissingLabel(2)));
+        let error = parse_labels("4213").unwrap_err();
+        assert_eq!(error.kind, Error::NotEnoughCups(4));
+    }
+}
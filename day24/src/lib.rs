@@ -0,0 +1,131 @@
+use aoc_common::{parse_lines, trace, Automaton, Neighbourhood, ParseError, Solution};
+
+/// A black tile with 0 or more than 2 black neighbours becomes white, a white
+/// tile with 2 black neighbours becomes black.
+const RULE: &str = "B2/S12";
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, aoc_common::Error> {
+    Ok(initial_floor(input)?.live_count())
+}
+
+pub fn part2(input: &str) -> Result<usize, aoc_common::Error> {
+    let mut floor = initial_floor(input)?;
+    for day in 0..100 {
+        floor.step();
+        trace!("Day {}: {}", day + 1, floor.live_count());
+    }
+
+    Ok(floor.live_count())
+}
+
+/// Black tiles are the live cells of an automaton on cube coordinates.
+fn initial_floor(input: &str) -> Result<Automaton<3>, aoc_common::Error> {
+    let mut floor = Automaton::new(Neighbourhood::Hex, RULE.parse()?)?;
+    for position in parse_lines(input, hex_position)? {
+        floor.toggle(position);
+    }
+
+    Ok(floor)
+}
+
+/// Tile at the end of a non empty path of directions from the reference tile.
+fn hex_position(line: &str) -> Result<HexPosition, ParseError<Error>> {
+    if line.is_empty() {
+        return Err(ParseError::new(line, line, Error::EmptyPath));
+    }
+
+    let mut position = [0, 0, 0];
+
+    let mut rest = line;
+    while let Some(first) = rest.chars().next() {
+        let length = if first == 'n' || first == 's' {
+            rest.char_indices().nth(2).map_or(rest.len(), |(i, _)| i)
+        } else {
+            first.len_utf8()
+        };
+
+        let dir = &rest[..length];
+        rest = &rest[length..];
+        match dir {
+            "e" => {
+                position[0] += 1;
+                position[1] -= 1;
+            }
+            "w" => {
+                position[0] -= 1;
+                position[1] += 1;
+            }
+            "ne" => {
+                position[0] += 1;
+                position[2] -= 1;
+            }
+            "se" => {
+                position[1] -= 1;
+                position[2] += 1;
+            }
+            "nw" => {
+                position[1] += 1;
+                position[2] -= 1;
+            }
+            "sw" => {
+                position[0] -= 1;
+                position[2] += 1;
+            }
+            _ => return Err(ParseError::new(line, dir, Error::UnknownDirection)),
+        }
+    }
+
+    Ok(position)
+}
+
+type HexPosition = [i32; 3];
+
+#[derive(Debug)]
+enum Error {
+    UnknownDirection,
+    /// A blank line, which would flip the reference tile.
+    EmptyPath,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 10);
+    }
+
+    #[test]
+    fn can_solve_part2_example() {
+        assert_eq!(part2(include_str!("../example_input")).unwrap(), 2208);
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let error = part1("nwwswee\nesenwx").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: UnknownDirection at `x`"
+        );
+        let error = part1("esen").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: UnknownDirection at `n`"
+        );
+        let error = part1("esew\n\nnwwswee").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: EmptyPath at ``");
+    }
+}
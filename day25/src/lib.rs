@@ -0,0 +1,103 @@
+use aoc_common::{parse_lines, trace, ParseError, Solution};
+use std::num::ParseIntError;
+
+/// Modulus of the transformations.
+const MODULUS: u64 = 20201227;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part1(&self, input: &str) -> Result<String, aoc_common::Error> {
+        Ok(part1(input)?.to_string())
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, aoc_common::Error> {
+    let public_keys = parse_lines(input, parse_public_key)?;
+    match public_keys[..] {
+        [card_public_key, door_public_key] => {
+            Ok(compute_encryption_key(card_public_key, door_public_key))
+        }
+        _ => Err(aoc_common::Error::Solver(
+            "Expected the card and door public keys".into(),
+        )),
+    }
+}
+
+/// Public keys are transformations of 7, which reach every number between 1
+/// and the modulus excluded.
+fn parse_public_key(line: &str) -> Result<u64, ParseError<Error>> {
+    let key = line
+        .parse()
+        .map_err(|e| ParseError::new(line, line, Error::ParsePublicKeyError(e)))?;
+    if key == 0 || key >= MODULUS {
+        return Err(ParseError::new(line, line, Error::PublicKeyOutOfRange(key)));
+    }
+
+    Ok(key)
+}
+
+fn compute_encryption_key(card_public_key: u64, door_public_key: u64) -> u64 {
+    let card_loop_size = find_loop_size(7, card_public_key);
+    let door_loop_size = find_loop_size(7, door_public_key);
+    trace!("{}, {}", card_loop_size, door_loop_size);
+
+    let card_encryption_key = transform_subject_number(door_public_key, card_loop_size);
+    let door_encryption_key = transform_subject_number(card_public_key, door_loop_size);
+    assert_eq!(
+        card_encryption_key, door_encryption_key,
+        "Card calculated encryption key != Door calculated encryption key"
+    );
+    card_encryption_key
+}
+
+fn find_loop_size(initial_subject_number: u64, card_public_key: u64) -> u64 {
+    let mut loop_size = 0;
+    let mut res = 1;
+    loop {
+        res = (res * initial_subject_number) % MODULUS;
+        loop_size += 1;
+        if res == card_public_key {
+            return loop_size;
+        }
+    }
+}
+
+fn transform_subject_number(subject_number: u64, loop_size: u64) -> u64 {
+    let mut value = 1;
+    for _ in 0..loop_size {
+        value *= subject_number;
+        value %= MODULUS;
+    }
+
+    value
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ParsePublicKeyError(ParseIntError),
+    PublicKeyOutOfRange(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part1_example() {
+        assert_eq!(part1(include_str!("../example_input")).unwrap(), 14897079);
+    }
+
+    #[test]
+    fn reports_invalid_public_keys() {
+        let error = part1("5764801\n1780772x").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 1: ParsePublicKeyError"));
+        let error = part1("5764801\n20201227").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: PublicKeyOutOfRange(20201227) at `20201227`"
+        );
+    }
+}